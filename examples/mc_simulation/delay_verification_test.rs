@@ -5,6 +5,7 @@ use components::component_states::*;
 use components::fifo_memory::FIFOMemory;
 use simulation_builder::*;
 use rsim::core::execution::config::{ConcurrencyMode, SimulationConfig};
+use rsim::core::verification::{Invariant, OnViolation};
 use std::time::Instant;
 
 /// Test results for analysis
@@ -65,6 +66,14 @@ fn run_simulation_test(
     let mut engine = sim.build()?;
     engine.build_execution_order()?;
     
+    // Every buffer must stay within [0, capacity] at the end of each cycle
+    engine.add_monitor(
+        Invariant::for_each_memory("fifo within capacity", |fifo: &FIFOMemory| {
+            fifo.data_count >= 0 && fifo.data_count <= fifo.capacity
+        }),
+        OnViolation::Count,
+    )?;
+    
     // Run simulation
     for cycle in 1..=cycles {
         engine.cycle()?;
@@ -76,6 +85,13 @@ fn run_simulation_test(
     
     let execution_time = start_time.elapsed();
     
+    let monitor_report = engine.monitor_report();
+    if monitor_report.total_violations() == 0 {
+        println!("   ✅ Buffer invariants held in every cycle");
+    } else {
+        println!("   ❌ Buffer invariant violations:\n{}", monitor_report);
+    }
+    
     // Query results by measuring actual buffer contents instead of component states
     println!("   🔍 Measuring actual buffer contents...");
     
@@ -339,6 +339,12 @@ pub fn run_benchmark(
         }
     }
     
+    // Optional profiling: RSIM_PROFILE=<trace prefix> writes <prefix>-<mode>.json
+    let profile_prefix = std::env::var("RSIM_PROFILE").ok();
+    if profile_prefix.is_some() {
+        engine.enable_profiling();
+    }
+    
     // Measurement phase
     println!("⏱️  Measuring: {} cycles", config.cycles_to_run);
     let start_time = Instant::now();
@@ -354,6 +360,14 @@ pub fn run_benchmark(
     
     let duration = start_time.elapsed();
     
+    if let (Some(prefix), Some(profiler)) = (profile_prefix, engine.disable_profiling()) {
+        println!("\n🔍 Profile ({:?}):", mode);
+        print!("{}", profiler.report());
+        let trace_path = format!("{}-{}.json", prefix, format!("{:?}", mode).to_lowercase());
+        profiler.write_chrome_trace(&trace_path)?;
+        println!("  Chrome trace written to {}", trace_path);
+    }
+    
     Ok(BenchmarkResults::new(config, mode, duration))
 }
 
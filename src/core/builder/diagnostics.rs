@@ -0,0 +1,399 @@
+use crate::core::builder::simulation_builder::ComponentInstance;
+use crate::core::execution::execution_order::ExecutionOrderBuilder;
+use crate::core::types::ComponentId;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Severity of a model diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Informational finding, not necessarily a problem
+    Info,
+    /// Suspicious model structure that is likely a mistake
+    Warning,
+    /// Structural problem that will prevent the model from running
+    Error,
+}
+
+/// Kind of finding reported by the model linter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DiagnosticKind {
+    /// Processing component with no port or memory connections at all
+    UnconnectedComponent,
+    /// Memory module that no component connects to
+    UnusedMemory,
+    /// Memory module read by components but written by none
+    UnwrittenMemory,
+    /// Memory module with multiple writers in the same parallel sub-level
+    ConflictingMemoryWriters,
+    /// Stage chain deeper than the configured limit
+    DeepStageChain,
+    /// Cycle in the component dependency graph
+    DependencyCycle,
+}
+
+/// A single finding produced by the model linter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// What was found
+    pub kind: DiagnosticKind,
+    /// How serious it is
+    pub severity: Severity,
+    /// Components involved (the memory module first, where one is involved)
+    pub components: Vec<ComponentId>,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{:?}] {:?}: {}", self.severity, self.kind, self.message)
+    }
+}
+
+/// Thresholds controlling which findings are reported
+#[derive(Debug, Clone)]
+pub struct DiagnosticsConfig {
+    /// Minimum number of readers before an unwritten memory is flagged
+    pub unwritten_memory_min_readers: usize,
+    /// Maximum number of stages before the chain is considered suspiciously deep
+    pub max_stage_depth: usize,
+}
+
+impl Default for DiagnosticsConfig {
+    fn default() -> Self {
+        Self {
+            unwritten_memory_min_readers: 2,
+            max_stage_depth: 32,
+        }
+    }
+}
+
+/// Structured result of linting a model at build time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiagnosticsReport {
+    /// All findings, ordered by kind and then by component IDs
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl DiagnosticsReport {
+    /// Check if there are no findings at all
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// Get all findings of at least the given severity
+    pub fn at_least(&self, severity: Severity) -> Vec<&Diagnostic> {
+        self.diagnostics.iter().filter(|d| d.severity >= severity).collect()
+    }
+
+    /// Get all findings of a given kind
+    pub fn of_kind(&self, kind: DiagnosticKind) -> Vec<&Diagnostic> {
+        self.diagnostics.iter().filter(|d| d.kind == kind).collect()
+    }
+
+    /// Check if any finding is a warning or worse
+    pub fn has_warnings(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.severity >= Severity::Warning)
+    }
+
+    /// Check if any finding is an error
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.severity == Severity::Error)
+    }
+
+    /// Fail if the report contains warnings or errors (useful for CI)
+    pub fn deny_warnings(&self) -> Result<(), String> {
+        let findings = self.at_least(Severity::Warning);
+        if findings.is_empty() {
+            return Ok(());
+        }
+        let lines: Vec<String> = findings.iter().map(|d| d.to_string()).collect();
+        Err(format!("Model diagnostics found {} warning(s): [{}]", lines.len(), lines.join(", ")))
+    }
+}
+
+impl std::fmt::Display for DiagnosticsReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for diagnostic in &self.diagnostics {
+            writeln!(f, "{}", diagnostic)?;
+        }
+        Ok(())
+    }
+}
+
+/// Static analysis of a model's structure, run before the engine is built
+pub struct ModelLinter;
+
+impl ModelLinter {
+    /// Lint the components and connections of a model
+    pub fn lint(
+        components: &HashMap<ComponentId, ComponentInstance>,
+        connections: &HashMap<(ComponentId, String), Vec<(ComponentId, String)>>,
+        memory_connections: &HashMap<(ComponentId, String), ComponentId>,
+        stimulus_targets: &[(ComponentId, String)],
+        initialized_memories: &[ComponentId],
+        config: &DiagnosticsConfig,
+    ) -> DiagnosticsReport {
+        let mut diagnostics = Vec::new();
+
+        // Memory ID -> (reading components, writing components)
+        let mut memory_access: BTreeMap<ComponentId, (BTreeSet<ComponentId>, BTreeSet<ComponentId>)> = BTreeMap::new();
+        for ((comp_id, port), memory_id) in memory_connections {
+            let access = components.get(comp_id)
+                .and_then(|c| c.module.as_processing())
+                .and_then(|p| p.memory_port_access(port))
+                .unwrap_or_default();
+            let entry = memory_access.entry(memory_id.clone()).or_default();
+            if access.reads() {
+                entry.0.insert(comp_id.clone());
+            }
+            if access.writes() {
+                entry.1.insert(comp_id.clone());
+            }
+        }
+
+        let mut connected: BTreeSet<&ComponentId> = BTreeSet::new();
+        for ((source_id, _), targets) in connections {
+            connected.insert(source_id);
+            connected.extend(targets.iter().map(|(target_id, _)| target_id));
+        }
+        connected.extend(memory_connections.keys().map(|(comp_id, _)| comp_id));
+        connected.extend(stimulus_targets.iter().map(|(comp_id, _)| comp_id));
+
+        let mut ids: Vec<&ComponentId> = components.keys().collect();
+        ids.sort();
+
+        for id in &ids {
+            let instance = &components[*id];
+            if instance.module.is_processing() && !connected.contains(id) {
+                diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::UnconnectedComponent,
+                    severity: Severity::Warning,
+                    components: vec![(*id).clone()],
+                    message: format!("Component '{}' has no port or memory connections", id),
+                });
+            }
+            if instance.module.is_memory() && !memory_access.contains_key(*id) {
+                diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::UnusedMemory,
+                    severity: Severity::Warning,
+                    components: vec![(*id).clone()],
+                    message: format!("Memory '{}' is not connected to any component", id),
+                });
+            }
+        }
+
+        for (memory_id, (readers, writers)) in &memory_access {
+            // Read-only memory with initial contents is a lookup table, not a mistake
+            let initialized = initialized_memories.contains(memory_id);
+            if writers.is_empty() && !initialized && readers.len() >= config.unwritten_memory_min_readers {
+                let mut involved = vec![memory_id.clone()];
+                involved.extend(readers.iter().cloned());
+                diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::UnwrittenMemory,
+                    severity: Severity::Warning,
+                    components: involved,
+                    message: format!("Memory '{}' is read by {} components but written by none",
+                                     memory_id, readers.len()),
+                });
+            }
+        }
+
+        let processing_ids: Vec<ComponentId> = ids.iter()
+            .filter(|id| components[**id].module.is_processing())
+            .map(|id| (*id).clone())
+            .collect();
+
+        match ExecutionOrderBuilder::build_execution_order_with_sub_levels(&processing_ids, connections) {
+            Ok(stages) => {
+                for (stage_idx, stage) in stages.iter().enumerate() {
+                    for (sub_idx, sub_level) in stage.sub_levels.iter().enumerate() {
+                        let members: BTreeSet<&ComponentId> = sub_level.components.iter().collect();
+                        for (memory_id, (_, writers)) in &memory_access {
+                            let conflicting: Vec<ComponentId> = writers.iter()
+                                .filter(|w| members.contains(w))
+                                .cloned()
+                                .collect();
+                            if conflicting.len() > 1 {
+                                let names: Vec<String> = conflicting.iter().map(|c| c.to_string()).collect();
+                                let mut involved = vec![memory_id.clone()];
+                                involved.extend(conflicting);
+                                diagnostics.push(Diagnostic {
+                                    kind: DiagnosticKind::ConflictingMemoryWriters,
+                                    severity: Severity::Warning,
+                                    components: involved,
+                                    message: format!(
+                                        "Memory '{}' has {} writers in stage {} sub-level {}: {}",
+                                        memory_id, names.len(), stage_idx, sub_idx, names.join(", ")
+                                    ),
+                                });
+                            }
+                        }
+                    }
+                }
+
+                if stages.len() > config.max_stage_depth {
+                    diagnostics.push(Diagnostic {
+                        kind: DiagnosticKind::DeepStageChain,
+                        severity: Severity::Warning,
+                        components: stages.last()
+                            .map(|s| s.sub_levels.iter().flat_map(|l| l.components.clone()).collect())
+                            .unwrap_or_default(),
+                        message: format!("Execution order has {} stages (limit {})",
+                                         stages.len(), config.max_stage_depth),
+                    });
+                }
+            }
+            Err(error) => {
+                diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::DependencyCycle,
+                    severity: Severity::Error,
+                    components: Vec::new(),
+                    message: error,
+                });
+            }
+        }
+
+        diagnostics.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.components.cmp(&b.components)));
+        DiagnosticsReport { diagnostics }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builder::simulation_builder::Simulation;
+    use crate::core::components::module::{PortSpec, ProcessorModule};
+    use crate::core::components::{Component, Cycle, MemoryComponent, PortType};
+    use crate::core::components::state::MemoryData;
+
+    #[derive(Clone)]
+    struct Cell {
+        value: i64,
+    }
+
+    impl MemoryData for Cell {}
+
+    impl Cycle for Cell {
+        type Output = i64;
+
+        fn cycle(&mut self) -> Option<Self::Output> {
+            Some(self.value)
+        }
+    }
+
+    impl MemoryComponent for Cell {
+        fn define_ports() -> Vec<(String, PortType)> {
+            vec![
+                ("input".to_string(), PortType::Input),
+                ("output".to_string(), PortType::Output),
+            ]
+        }
+    }
+
+    struct Writer;
+
+    impl Component for Writer {
+        fn define_ports() -> Vec<(String, PortType)> {
+            vec![("mem".to_string(), PortType::Memory)]
+        }
+
+        fn into_module() -> ProcessorModule {
+            ProcessorModule::new("Writer", vec![], vec![], vec![PortSpec::memory("mem")], |_, _| Ok(()))
+        }
+    }
+
+    struct Reader;
+
+    impl Component for Reader {
+        fn define_ports() -> Vec<(String, PortType)> {
+            vec![("mem".to_string(), PortType::Memory)]
+        }
+
+        fn into_module() -> ProcessorModule {
+            ProcessorModule::new("Reader", vec![], vec![], vec![PortSpec::memory("mem").read_only()], |_, _| Ok(()))
+        }
+    }
+
+    struct Relay;
+
+    impl Component for Relay {
+        fn define_ports() -> Vec<(String, PortType)> {
+            vec![
+                ("in".to_string(), PortType::Input),
+                ("out".to_string(), PortType::Output),
+            ]
+        }
+
+        fn into_module() -> ProcessorModule {
+            ProcessorModule::new("Relay", vec![PortSpec::input("in")], vec![PortSpec::output("out")], vec![], |_, _| Ok(()))
+        }
+    }
+
+    #[test]
+    fn test_unconnected_and_unused() {
+        let mut sim = Simulation::new();
+        let relay = sim.add_component(Relay);
+        let cell = sim.add_memory_component(Cell { value: 0 });
+
+        let report = sim.diagnostics();
+        assert_eq!(report.of_kind(DiagnosticKind::UnconnectedComponent)[0].components, vec![relay]);
+        assert_eq!(report.of_kind(DiagnosticKind::UnusedMemory)[0].components, vec![cell]);
+        assert!(report.has_warnings());
+        assert!(report.deny_warnings().is_err());
+    }
+
+    #[test]
+    fn test_unwritten_memory() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let cell = sim.add_memory_component(Cell { value: 0 });
+        let r1 = sim.add_component(Reader);
+        let r2 = sim.add_component(Reader);
+        sim.connect_memory(r1.output("mem"), cell.clone())?;
+        sim.connect_memory(r2.output("mem"), cell.clone())?;
+
+        let report = sim.diagnostics();
+        let findings = report.of_kind(DiagnosticKind::UnwrittenMemory);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].components[0], cell);
+        assert!(report.of_kind(DiagnosticKind::ConflictingMemoryWriters).is_empty());
+
+        // Initialized read-only memory is a lookup table
+        sim.init_memory(&cell, "value", Cell { value: 1 });
+        assert!(sim.diagnostics().of_kind(DiagnosticKind::UnwrittenMemory).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_conflicting_writers_in_sub_level() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let cell = sim.add_memory_component(Cell { value: 0 });
+        let w1 = sim.add_component(Writer);
+        let w2 = sim.add_component(Writer);
+        sim.connect_memory(w1.output("mem"), cell.clone())?;
+        sim.connect_memory(w2.output("mem"), cell.clone())?;
+
+        let (engine, report) = sim.build_with_diagnostics()?;
+        let findings = report.of_kind(DiagnosticKind::ConflictingMemoryWriters);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].components.len(), 3);
+        assert_eq!(engine.diagnostics(), &report);
+        Ok(())
+    }
+
+    #[test]
+    fn test_deep_stage_chain() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        sim.set_diagnostics_config(DiagnosticsConfig { max_stage_depth: 3, ..DiagnosticsConfig::default() });
+        let relays: Vec<ComponentId> = (0..5).map(|_| sim.add_component(Relay)).collect();
+        for pair in relays.windows(2) {
+            sim.connect(pair[0].output("out"), pair[1].input("in"))?;
+        }
+
+        let report = sim.diagnostics();
+        assert_eq!(report.of_kind(DiagnosticKind::DeepStageChain).len(), 1);
+        assert!(report.of_kind(DiagnosticKind::UnconnectedComponent).is_empty());
+        Ok(())
+    }
+}
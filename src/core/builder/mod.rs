@@ -1,4 +1,6 @@
 pub mod simulation_builder;
+pub mod diagnostics;
 
 // Re-export commonly used types
-pub use simulation_builder::{Simulation, SimulationExt};
\ No newline at end of file
+pub use simulation_builder::{Simulation, SimulationExt};
+pub use diagnostics::{Diagnostic, DiagnosticKind, DiagnosticsConfig, DiagnosticsReport, ModelLinter, Severity};
\ No newline at end of file
@@ -1,10 +1,16 @@
+use crate::core::builder::diagnostics::{DiagnosticsConfig, DiagnosticsReport, ModelLinter};
 use crate::core::components::module::ComponentModule;
 use crate::core::components::state::MemoryData;
 use crate::core::components::traits::{Component, MemoryComponent};
 use crate::core::execution::cycle_engine::CycleEngine;
 use crate::core::execution::config::SimulationConfig;
+use crate::core::execution::clock::ClockDomain;
+use crate::core::memory::channel::{Channel, CHANNEL_ADDRESS};
+use crate::core::stimulus::StimulusSource;
+use crate::core::probes::{Probe, ProbeTarget};
 use crate::core::types::{ComponentId, OutputPort, InputPort, MemoryPort};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
 
 /// Simplified component instance for direct module usage
 pub struct ComponentInstance {
@@ -12,6 +18,14 @@ pub struct ComponentInstance {
     pub module: ComponentModule,
 }
 
+/// Initial value for a memory address, applied when the simulation is built
+struct MemoryInit {
+    memory_id: ComponentId,
+    address: String,
+    type_name: &'static str,
+    value: Box<dyn std::any::Any + Send>,
+}
+
 /// Imperative API for creating and configuring simulations with direct module creation
 /// 
 /// This simplified builder removes the component manager registration system
@@ -27,6 +41,18 @@ pub struct Simulation {
     id_counter: std::sync::atomic::AtomicU64,
     /// Simulation configuration
     config: Option<SimulationConfig>,
+    /// Thresholds used by the build-time model linter
+    diagnostics_config: DiagnosticsConfig,
+    /// External stimulus sources: (component_id, input_port) -> source
+    stimuli: HashMap<(ComponentId, String), Box<dyn StimulusSource>>,
+    /// Probes to attach to the built engine
+    probes: Vec<Probe>,
+    /// Control connections: (source_id, source_port) -> gated components
+    controls: HashMap<(ComponentId, String), Vec<ComponentId>>,
+    /// Clock domains of components that do not tick every cycle
+    clock_domains: HashMap<ComponentId, ClockDomain>,
+    /// Initial memory contents, validated and applied at build
+    memory_inits: Vec<MemoryInit>,
 }
 
 impl Simulation {
@@ -38,6 +64,12 @@ impl Simulation {
             memory_connections: HashMap::new(),
             id_counter: std::sync::atomic::AtomicU64::new(0),
             config: None,
+            diagnostics_config: DiagnosticsConfig::default(),
+            stimuli: HashMap::new(),
+            probes: Vec::new(),
+            controls: HashMap::new(),
+            clock_domains: HashMap::new(),
+            memory_inits: Vec::new(),
         }
     }
     
@@ -49,6 +81,12 @@ impl Simulation {
             memory_connections: HashMap::new(),
             id_counter: std::sync::atomic::AtomicU64::new(0),
             config: Some(config),
+            diagnostics_config: DiagnosticsConfig::default(),
+            stimuli: HashMap::new(),
+            probes: Vec::new(),
+            controls: HashMap::new(),
+            clock_domains: HashMap::new(),
+            memory_inits: Vec::new(),
         }
     }
 
@@ -142,6 +180,10 @@ impl Simulation {
                                   target_port, target_id));
             }
         }
+        if self.stimuli.contains_key(&(target_id.clone(), target_port.clone())) {
+            return Err(format!("Input port '{}' on component '{}' is already driven by a stimulus", 
+                              target_port, target_id));
+        }
         
         // If all validations pass, make the connection (single target per output)
         self.connections.insert((source_id, source_port), vec![(target_id, target_port)]);
@@ -213,50 +255,247 @@ impl Simulation {
             return Err(format!("Component '{}' is not a memory component", memory_id));
         }
         
+        self.validate_memory_port(&component_port)?;
+        
+        // If all validations pass, make the connection
+        self.memory_connections.insert((comp_id, port_name), memory_id);
+        Ok(())
+    }
+
+    /// Check a component memory port exists and is not connected yet
+    fn validate_memory_port(&self, component_port: &MemoryPort) -> Result<(), String> {
+        let comp_id = component_port.component_id();
+        let port_name = component_port.port_name();
+        
         // Validate that the source component exists
-        if !self.components.contains_key(component_port.component_id()) {
-            return Err(format!("Source component '{}' not found", component_port.component_id()));
-        }
+        let source_component = self.components.get(comp_id)
+            .ok_or_else(|| format!("Source component '{}' not found", comp_id))?;
         
         // Check for duplicate connections (each memory port can only be connected once)
-        let connection_key = (comp_id.clone(), port_name.clone());
-        if self.memory_connections.contains_key(&connection_key) {
+        if self.memory_connections.contains_key(&(comp_id.clone(), port_name.to_string())) {
             return Err(format!("Memory port '{}' on component '{}' is already connected", 
                               port_name, comp_id));
         }
         
         // Validate that the source component has the specified port
-        let source_component = self.components.get(component_port.component_id()).unwrap();
         if let Some(processor) = source_component.module.as_processing() {
-            if !processor.has_memory_port(&port_name) {
+            if !processor.has_memory_port(port_name) {
                 return Err(format!("Component '{}' does not have memory port '{}'", 
-                                  component_port.component_id(), port_name));
+                                  comp_id, port_name));
             }
         } else {
-            return Err(format!("Source component '{}' is not a processing component", component_port.component_id()));
+            return Err(format!("Source component '{}' is not a processing component", comp_id));
         }
+        Ok(())
+    }
+
+    /// Link a producer and a consumer memory port through a new handshake channel
+    ///
+    /// The channel is stored at `CHANNEL_ADDRESS` of a new memory component,
+    /// whose ID is returned. See `Channel` for the handshake.
+    pub fn connect_channel<T: MemoryData>(&mut self, producer: MemoryPort, consumer: MemoryPort, channel: Channel<T>) -> Result<ComponentId, String> {
+        if producer.component_id() == consumer.component_id() && producer.port_name() == consumer.port_name() {
+            return Err(format!("Channel producer and consumer are the same port '{}' on component '{}'", 
+                              producer.port_name(), producer.component_id()));
+        }
+        self.validate_memory_port(&producer)?;
+        self.validate_memory_port(&consumer)?;
+        
+        let memory_id = self.add_memory_component(channel.clone());
+        self.init_memory(&memory_id, CHANNEL_ADDRESS, channel);
+        self.connect_memory_port(producer, memory_id.clone())?;
+        self.connect_memory_port(consumer, memory_id.clone())?;
+        Ok(memory_id)
+    }
+
+    /// Gate a component from a bool output port of another component
+    ///
+    /// `false` suspends the target and `true` resumes it, from the cycle after
+    /// the event. See `CycleEngine::suspend` for what suspension means.
+    pub fn connect_control(&mut self, source: OutputPort, target: ComponentId) -> Result<(), String> {
+        let source_id = source.component_id().clone();
+        let source_port = source.port_name().to_string();
         
-        // If all validations pass, make the connection
-        self.memory_connections.insert(connection_key, memory_id);
+        let source_component = self.components.get(&source_id)
+            .ok_or_else(|| format!("Source component '{}' not found", source_id))?;
+        if let Some(processor) = source_component.module.as_processing() {
+            if !processor.has_output_port(&source_port) {
+                return Err(format!("Component '{}' does not have output port '{}'", 
+                                  source_id, source_port));
+            }
+        } else {
+            return Err(format!("Source component '{}' is not a processing component", source_id));
+        }
+        if !self.components.contains_key(&target) {
+            return Err(format!("Target component '{}' not found", target));
+        }
+        
+        let targets = self.controls.entry((source_id, source_port)).or_default();
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+        Ok(())
+    }
+
+    /// Assign a component to a clock domain; see `CycleEngine::set_clock_domain`
+    pub fn set_clock_domain(&mut self, id: &ComponentId, domain: ClockDomain) -> Result<(), String> {
+        if !self.components.contains_key(id) {
+            return Err(format!("Component '{}' not found", id));
+        }
+        domain.validate()?;
+        self.clock_domains.insert(id.clone(), domain);
+        Ok(())
+    }
+
+    /// Set the initial value of a memory address
+    ///
+    /// The value is readable from the first cycle. The memory, address and
+    /// value type are validated when the simulation is built.
+    pub fn init_memory<T: MemoryData>(&mut self, memory_id: &ComponentId, address: &str, value: T) {
+        self.memory_inits.push(MemoryInit {
+            memory_id: memory_id.clone(),
+            address: address.to_string(),
+            type_name: std::any::type_name::<T>(),
+            value: Box::new(value),
+        });
+    }
+
+    /// Set the initial contents of a memory from a JSON file of `{"address": value}` entries
+    pub fn init_memory_from_file<T, P>(&mut self, memory_id: &ComponentId, path: P) -> Result<(), String>
+    where
+        T: MemoryData + serde::de::DeserializeOwned,
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        let entries: BTreeMap<String, T> = serde_json::from_str(&text)
+            .map_err(|e| format!("Failed to parse '{}': {}", path.display(), e))?;
+        for (address, value) in entries {
+            self.init_memory(memory_id, &address, value);
+        }
+        Ok(())
+    }
+
+    /// Drive a component input port from an external stimulus source
+    ///
+    /// The source is polled once per cycle and its values are delivered to the
+    /// port as events, as if an upstream component had produced them.
+    pub fn add_stimulus<S: StimulusSource + 'static>(&mut self, target: InputPort, source: S) -> Result<(), String> {
+        let target_id = target.component_id().clone();
+        let target_port = target.port_name().to_string();
+        
+        // Validate that the target component has the specified input port
+        let target_component = self.components.get(&target_id)
+            .ok_or_else(|| format!("Target component '{}' not found", target_id))?;
+        if let Some(processor) = target_component.module.as_processing() {
+            if !processor.has_input_port(&target_port) {
+                return Err(format!("Component '{}' does not have input port '{}'", 
+                                  target_id, target_port));
+            }
+        } else {
+            return Err(format!("Target component '{}' is not a processing component", target_id));
+        }
+        
+        // A stimulus is a driver like any other: one per input port
+        for targets in self.connections.values() {
+            if targets.iter().any(|(tid, tport)| tid == &target_id && tport == &target_port) {
+                return Err(format!("Input port '{}' on component '{}' is already connected", 
+                                  target_port, target_id));
+            }
+        }
+        let key = (target_id, target_port);
+        if self.stimuli.contains_key(&key) {
+            return Err(format!("Input port '{}' on component '{}' is already driven by a stimulus", 
+                              key.1, key.0));
+        }
+        
+        self.stimuli.insert(key, Box::new(source));
+        Ok(())
+    }
+
+    /// Attach a probe to an output port or memory address
+    pub fn add_probe(&mut self, probe: Probe) -> Result<(), String> {
+        match &probe.target {
+            ProbeTarget::Output { component_id, port } => {
+                let component = self.components.get(component_id)
+                    .ok_or_else(|| format!("Probed component '{}' not found", component_id))?;
+                match component.module.as_processing() {
+                    Some(processor) if processor.has_output_port(port) => {}
+                    _ => return Err(format!("Component '{}' does not have output port '{}'", component_id, port)),
+                }
+            }
+            ProbeTarget::Memory { memory_id, .. } => {
+                let memory = self.components.get(memory_id)
+                    .ok_or_else(|| format!("Probed memory component '{}' not found", memory_id))?;
+                if !memory.module.is_memory() {
+                    return Err(format!("Component '{}' is not a memory component", memory_id));
+                }
+            }
+        }
+        if self.probes.iter().any(|p| p.name == probe.name) {
+            return Err(format!("Probe '{}' is already registered", probe.name));
+        }
+        self.probes.push(probe);
         Ok(())
     }
 
+    /// Set the thresholds used by the build-time model linter
+    pub fn set_diagnostics_config(&mut self, config: DiagnosticsConfig) {
+        self.diagnostics_config = config;
+    }
+
+    /// Lint the model without building it
+    pub fn diagnostics(&self) -> DiagnosticsReport {
+        let stimulus_targets: Vec<(ComponentId, String)> = self.stimuli.keys().cloned().collect();
+        let initialized_memories: Vec<ComponentId> = self.memory_inits.iter().map(|init| init.memory_id.clone()).collect();
+        ModelLinter::lint(
+            &self.components,
+            &self.connections,
+            &self.memory_connections,
+            &stimulus_targets,
+            &initialized_memories,
+            &self.diagnostics_config,
+        )
+    }
+
+    /// Build the simulation and return the diagnostics report alongside the engine
+    pub fn build_with_diagnostics(self) -> Result<(CycleEngine, DiagnosticsReport), String> {
+        let engine = self.build()?;
+        let report = engine.diagnostics().clone();
+        Ok((engine, report))
+    }
+
     /// Build the simulation into a CycleEngine
+    ///
+    /// The diagnostics report produced while building is available
+    /// through `CycleEngine::diagnostics()`.
     pub fn build(self) -> Result<CycleEngine, String> {
         // Validate connections
         self.validate_connections()?;
+        self.validate_memory_inits()?;
+        
+        // Lint the model structure before components are moved into the engine
+        let report = self.diagnostics();
         
         // Get configuration (use default if not provided)
         let config = self.config.unwrap_or_default();
         
         // Create and configure cycle engine
         let mut cycle_engine = CycleEngine::new(config);
+        cycle_engine.set_diagnostics(report);
+        cycle_engine.set_next_component_index(self.id_counter.load(std::sync::atomic::Ordering::SeqCst));
         
         // Add all components to the cycle engine
         for (_, instance) in self.components {
             cycle_engine.register_component_instance(instance)?;
         }
         
+        // Place initial memory contents before cycle 1
+        for init in self.memory_inits {
+            cycle_engine.init_memory(&init.memory_id, &init.address, init.value)?;
+        }
+        
         // Add all connections
         for ((source_id, source_port), targets) in self.connections {
             for (target_id, target_port) in targets {
@@ -269,6 +508,28 @@ impl Simulation {
             cycle_engine.connect_memory((component_id, port), memory_id)?;
         }
         
+        // Attach external stimulus sources
+        for (target, source) in self.stimuli {
+            cycle_engine.add_stimulus(target, source)?;
+        }
+        
+        // Attach control connections
+        for ((source_id, source_port), targets) in self.controls {
+            for target in targets {
+                cycle_engine.connect_control(source_id.output(&source_port), target)?;
+            }
+        }
+        
+        // Assign clock domains
+        for (id, domain) in self.clock_domains {
+            cycle_engine.set_clock_domain(&id, domain)?;
+        }
+        
+        // Attach probes
+        for probe in self.probes {
+            cycle_engine.add_probe(probe)?;
+        }
+        
         Ok(cycle_engine)
     }
 
@@ -307,6 +568,29 @@ impl Simulation {
         Ok(())
     }
 
+    /// Validate initial memory contents: target memory, address and value type
+    fn validate_memory_inits(&self) -> Result<(), String> {
+        let mut seen = HashSet::new();
+        for init in &self.memory_inits {
+            let component = self.components.get(&init.memory_id)
+                .ok_or_else(|| format!("Memory component '{}' not found", init.memory_id))?;
+            let memory = component.module.as_memory()
+                .ok_or_else(|| format!("Component '{}' is not a memory component", init.memory_id))?;
+            if init.address.is_empty() {
+                return Err(format!("Initial value for memory '{}' has an empty address", init.memory_id));
+            }
+            if memory.data_type_name() != init.type_name {
+                return Err(format!("Initial value for '{}[{}]' has type '{}', but the memory stores '{}'",
+                                  init.memory_id, init.address, init.type_name, memory.data_type_name()));
+            }
+            if !seen.insert((&init.memory_id, &init.address)) {
+                return Err(format!("Memory address '{}[{}]' is initialized more than once",
+                                  init.memory_id, init.address));
+            }
+        }
+        Ok(())
+    }
+
     /// Get all component IDs
     pub fn component_ids(&self) -> Vec<&ComponentId> {
         self.components.keys().collect()
@@ -1,18 +1,30 @@
 use crate::core::components::state::{ComponentState, MemoryData};
+use crate::core::entities::TokenFactory;
+use crate::core::stats::StatsRecorder;
+use crate::core::random::ComponentRng;
+use crate::core::replay::ExternalInputs;
 use crate::core::types::ComponentId;
 use crate::core::values::implementations::{TypedInputMap, EventInputMap};
 
 /// Evaluation context provided to component modules during evaluation.
 /// Contains inputs, memory access, and output collection.
-pub struct EvaluationContext<'a> {
+pub struct EvaluationContext<'a, 'm> {
     /// Event input values from connected components
     pub inputs: &'a EventInputMap,
     /// Memory proxy for type-safe memory access
-    pub memory: &'a mut crate::core::memory::proxy::MemoryProxy<'a>,
+    pub memory: &'a mut crate::core::memory::proxy::MemoryProxy<'m>,
     /// Component's current state (if any)
     pub state: Option<&'a mut dyn ComponentState>,
     /// Component ID for context
     pub component_id: &'a ComponentId,
+    /// Statistics recorder for counters, histograms and time-weighted averages
+    pub stats: &'a mut StatsRecorder,
+    /// Deterministic random stream for this component
+    pub rng: &'a mut ComponentRng,
+    /// Values read from outside the model, logged for record and replay
+    pub external: &'a mut ExternalInputs,
+    /// Creates entity tokens with IDs unique to this component and cycle
+    pub tokens: TokenFactory<'a>,
 }
 
 /// Legacy evaluation context for backward compatibility
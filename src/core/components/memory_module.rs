@@ -1,5 +1,5 @@
 use crate::core::components::state::MemoryData;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Trait for memory modules that can store and retrieve typed data
 pub trait MemoryModuleTrait: Send + Sync {
@@ -12,6 +12,9 @@ pub trait MemoryModuleTrait: Send + Sync {
     /// Write data to memory (type-erased)
     fn write_any(&mut self, address: &str, data: Box<dyn std::any::Any + Send>) -> bool;
     
+    /// Place data in both current state and snapshot, so it is readable before the first cycle
+    fn init_any(&mut self, address: &str, data: Box<dyn std::any::Any + Send>) -> bool;
+    
     /// Create a snapshot for next cycle
     fn create_snapshot(&mut self);
     
@@ -20,6 +23,12 @@ pub trait MemoryModuleTrait: Send + Sync {
     
     /// Get a clone of this memory module
     fn clone_module(&self) -> Box<dyn MemoryModuleTrait>;
+    
+    /// List all addresses present in the snapshot, sorted
+    fn addresses(&self) -> Vec<String>;
+    
+    /// Get the type name of the data stored in this module
+    fn data_type_name(&self) -> &'static str;
 }
 
 /// Concrete memory module implementation for specific data types
@@ -30,6 +39,8 @@ pub struct MemoryModule<T: MemoryData> {
     current_state: HashMap<String, T>,
     /// Snapshot from previous cycle (gets read from during cycle)
     snapshot: HashMap<String, T>,
+    /// Addresses written since the last snapshot; further writes are merged
+    written: HashSet<String>,
 }
 
 impl<T: MemoryData> MemoryModule<T> {
@@ -39,6 +50,7 @@ impl<T: MemoryData> MemoryModule<T> {
             memory_id: memory_id.to_string(),
             current_state: HashMap::new(),
             snapshot: HashMap::new(),
+            written: HashSet::new(),
         }
     }
 
@@ -60,8 +72,17 @@ impl<T: MemoryData> MemoryModule<T> {
     }
 
     /// Write to current state (affects next cycle)
+    ///
+    /// A second write to the same address before the next snapshot is
+    /// combined with the first through `MemoryData::merge`.
     pub fn write(&mut self, address: &str, data: T) -> bool {
-        self.current_state.insert(address.to_string(), data);
+        match self.current_state.get_mut(address) {
+            Some(existing) if self.written.contains(address) => existing.merge(data),
+            _ => {
+                self.current_state.insert(address.to_string(), data);
+                self.written.insert(address.to_string());
+            }
+        }
         true
     }
 }
@@ -80,8 +101,7 @@ impl<T: MemoryData + crate::core::components::traits::Cycle> MemoryModuleTrait f
 
     fn write_any(&mut self, address: &str, data: Box<dyn std::any::Any + Send>) -> bool {
         if let Ok(typed_data) = data.downcast::<T>() {
-            self.current_state.insert(address.to_string(), *typed_data);
-            true
+            self.write(address, *typed_data)
         } else {
             // Log type mismatch error for debugging
             eprintln!("Type mismatch error in memory module '{}' at address '{}': expected type '{}', got different type", 
@@ -90,8 +110,20 @@ impl<T: MemoryData + crate::core::components::traits::Cycle> MemoryModuleTrait f
         }
     }
 
+    fn init_any(&mut self, address: &str, data: Box<dyn std::any::Any + Send>) -> bool {
+        match data.downcast::<T>() {
+            Ok(typed_data) => {
+                self.snapshot.insert(address.to_string(), (*typed_data).clone());
+                self.current_state.insert(address.to_string(), *typed_data);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
     fn create_snapshot(&mut self) {
         self.snapshot = self.current_state.clone();
+        self.written.clear();
     }
     
     fn cycle(&mut self) -> Result<(), String> {
@@ -107,6 +139,17 @@ impl<T: MemoryData + crate::core::components::traits::Cycle> MemoryModuleTrait f
             memory_id: self.memory_id.clone(),
             current_state: self.current_state.clone(),
             snapshot: self.snapshot.clone(),
+            written: self.written.clone(),
         })
     }
+
+    fn addresses(&self) -> Vec<String> {
+        let mut addresses: Vec<String> = self.snapshot.keys().cloned().collect();
+        addresses.sort();
+        addresses
+    }
+
+    fn data_type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
 }
\ No newline at end of file
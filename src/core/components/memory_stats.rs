@@ -11,6 +11,10 @@ pub struct MemoryStats {
     pub read_count: usize,
     /// Number of write operations in current cycle
     pub write_count: usize,
+    /// Number of read operations since the start of the run
+    pub total_read_count: usize,
+    /// Number of write operations since the start of the run
+    pub total_write_count: usize,
 }
 
 impl MemoryStats {
@@ -22,6 +26,8 @@ impl MemoryStats {
             memory_usage_bytes: 0,
             read_count: 0,
             write_count: 0,
+            total_read_count: 0,
+            total_write_count: 0,
         }
     }
     
@@ -41,11 +47,27 @@ impl MemoryStats {
     /// Increment read counter
     pub fn increment_reads(&mut self) {
         self.read_count += 1;
+        self.total_read_count += 1;
     }
     
     /// Increment write counter
     pub fn increment_writes(&mut self) {
         self.write_count += 1;
+        self.total_write_count += 1;
+    }
+    
+    /// Reset run-wide counters, e.g. at the end of a warm-up period
+    pub fn reset_total_counters(&mut self) {
+        self.total_read_count = 0;
+        self.total_write_count = 0;
+    }
+    
+    /// Add another set of access counts to this one
+    pub fn merge_counts(&mut self, other: &MemoryStats) {
+        self.read_count += other.read_count;
+        self.write_count += other.write_count;
+        self.total_read_count += other.total_read_count;
+        self.total_write_count += other.total_write_count;
     }
 }
 
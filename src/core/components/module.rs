@@ -2,7 +2,7 @@
 pub use super::processor_module::ProcessorModule;
 pub use super::memory_module::{MemoryModule, MemoryModuleTrait};
 pub use super::evaluation_context::{EvaluationContext, LegacyEvaluationContext, TypeSafeMemoryProxy};
-pub use super::port_specs::{PortSpec, PortType};
+pub use super::port_specs::{PortSpec, PortType, MemoryAccess};
 pub use super::memory_stats::MemoryStats;
 
 /// Enum representing different types of component modules
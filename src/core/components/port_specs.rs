@@ -9,6 +9,8 @@ pub struct PortSpec {
     pub required: bool,
     /// Optional description for documentation
     pub description: Option<String>,
+    /// Declared access mode (only meaningful for memory ports)
+    pub access: MemoryAccess,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -18,6 +20,33 @@ pub enum PortType {
     Memory,
 }
 
+/// Declared access mode of a memory port
+///
+/// The engine does not enforce this; build-time diagnostics use it
+/// to tell readers and writers of a memory module apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MemoryAccess {
+    /// Port both reads and writes the connected memory (default)
+    #[default]
+    ReadWrite,
+    /// Port only reads the connected memory
+    ReadOnly,
+    /// Port only writes the connected memory
+    WriteOnly,
+}
+
+impl MemoryAccess {
+    /// Check if this access mode reads memory
+    pub fn reads(&self) -> bool {
+        !matches!(self, MemoryAccess::WriteOnly)
+    }
+
+    /// Check if this access mode writes memory
+    pub fn writes(&self) -> bool {
+        !matches!(self, MemoryAccess::ReadOnly)
+    }
+}
+
 impl PortSpec {
     /// Create a new required input port
     pub fn input(name: &str) -> Self {
@@ -26,6 +55,7 @@ impl PortSpec {
             port_type: PortType::Input,
             required: true,
             description: None,
+            access: MemoryAccess::default(),
         }
     }
 
@@ -36,6 +66,7 @@ impl PortSpec {
             port_type: PortType::Input,
             required: false,
             description: None,
+            access: MemoryAccess::default(),
         }
     }
 
@@ -46,6 +77,7 @@ impl PortSpec {
             port_type: PortType::Output,
             required: false, // outputs are not "required" in the same sense
             description: None,
+            access: MemoryAccess::default(),
         }
     }
 
@@ -56,6 +88,7 @@ impl PortSpec {
             port_type: PortType::Memory,
             required: false, // memory ports are optional
             description: None,
+            access: MemoryAccess::default(),
         }
     }
 
@@ -76,4 +109,16 @@ impl PortSpec {
         self.required = true;
         self
     }
+
+    /// Declare that this memory port only reads the connected memory
+    pub fn read_only(mut self) -> Self {
+        self.access = MemoryAccess::ReadOnly;
+        self
+    }
+
+    /// Declare that this memory port only writes the connected memory
+    pub fn write_only(mut self) -> Self {
+        self.access = MemoryAccess::WriteOnly;
+        self
+    }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use crate::core::values::implementations::EventOutputMap;
 use super::evaluation_context::EvaluationContext;
-use super::port_specs::PortSpec;
+use super::port_specs::{PortSpec, MemoryAccess};
 
 /// Processing component module that defines stateless computation
 #[derive(Clone)]
@@ -81,4 +81,9 @@ impl ProcessorModule {
     pub fn has_memory_port(&self, name: &str) -> bool {
         self.memory_ports.iter().any(|p| p.name == name)
     }
+
+    /// Get the declared access mode of a memory port
+    pub fn memory_port_access(&self, name: &str) -> Option<MemoryAccess> {
+        self.memory_ports.iter().find(|p| p.name == name).map(|p| p.access)
+    }
 }
\ No newline at end of file
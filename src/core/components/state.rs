@@ -12,7 +12,15 @@ pub trait ComponentState: Send {
 
 /// Marker trait for data types that can be stored in memory components.
 /// This provides compile-time type safety for memory operations.
-pub trait MemoryData: Send + Sync + Clone + 'static {}
+pub trait MemoryData: Send + Sync + Clone + 'static {
+    /// Combine a value written to an address that was already written this cycle
+    ///
+    /// The default keeps the later write. Types that carry requests, such as
+    /// `Fifo`, override it to keep the requests of every writer.
+    fn merge(&mut self, other: Self) {
+        *self = other;
+    }
+}
 
 /// Errors that can occur during state management operations
 #[derive(Debug, Clone)]
@@ -60,4 +68,5 @@ impl MemoryData for i64 {}
 impl MemoryData for f64 {}
 impl MemoryData for String {}
 impl MemoryData for bool {}
-impl MemoryData for Vec<u8> {}
\ No newline at end of file
+impl MemoryData for Vec<u8> {}
+impl MemoryData for crate::core::values::typed_value::TypedValue {}
\ No newline at end of file
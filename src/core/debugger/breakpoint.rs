@@ -0,0 +1,197 @@
+use crate::core::types::ComponentId;
+use crate::core::values::inspect::ValueInspector;
+use std::any::Any;
+use std::fmt;
+
+/// Stop after a cycle in which a component was evaluated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakpoint {
+    pub component_id: ComponentId,
+    /// Only stop when the component also received or emitted an event on this port
+    pub port: Option<String>,
+}
+
+/// Comparison used by conditional watchpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    /// Parse `==`, `!=`, `<`, `<=`, `>` or `>=`
+    pub fn parse(op: &str) -> Result<Self, String> {
+        match op {
+            "==" => Ok(CompareOp::Eq),
+            "!=" => Ok(CompareOp::Ne),
+            "<" => Ok(CompareOp::Lt),
+            "<=" => Ok(CompareOp::Le),
+            ">" => Ok(CompareOp::Gt),
+            ">=" => Ok(CompareOp::Ge),
+            _ => Err(format!("Unknown comparison '{}'", op)),
+        }
+    }
+
+    /// Operator symbol
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompareOp::Eq => "==",
+            CompareOp::Ne => "!=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        }
+    }
+
+    fn holds(&self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering::*;
+        match self {
+            CompareOp::Eq => ordering == Equal,
+            CompareOp::Ne => ordering != Equal,
+            CompareOp::Lt => ordering == Less,
+            CompareOp::Le => ordering != Greater,
+            CompareOp::Gt => ordering == Greater,
+            CompareOp::Ge => ordering != Less,
+        }
+    }
+}
+
+/// When a watchpoint fires
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchCondition {
+    /// The value at the address changed
+    Changed,
+    /// The value starts meeting a comparison
+    ///
+    /// Numeric values are compared as numbers; other values support `==` and
+    /// `!=` against their `Debug` representation.
+    Compare { op: CompareOp, value: String },
+}
+
+impl fmt::Display for WatchCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WatchCondition::Changed => write!(f, "changes"),
+            WatchCondition::Compare { op, value } => write!(f, "{} {}", op.as_str(), value),
+        }
+    }
+}
+
+/// Stop when a memory address changes or meets a condition
+pub struct Watchpoint {
+    pub memory_id: ComponentId,
+    pub address: String,
+    pub condition: WatchCondition,
+    /// Value at the previous check, for `Changed`
+    last_value: Option<Box<dyn Any + Send>>,
+    /// Whether the comparison held at the previous check, for `Compare`
+    last_held: bool,
+}
+
+impl Watchpoint {
+    pub(crate) fn new(memory_id: ComponentId, address: &str, condition: WatchCondition) -> Self {
+        Self {
+            memory_id,
+            address: address.to_string(),
+            condition,
+            last_value: None,
+            last_held: false,
+        }
+    }
+
+    /// Check the current value; `true` if the watchpoint fires
+    ///
+    /// Comparisons fire only on the check where they start to hold, so
+    /// continuing past a hit does not stop again on the next cycle.
+    pub(crate) fn check(&mut self, value: Option<Box<dyn Any + Send>>, inspector: &ValueInspector) -> Result<bool, String> {
+        match &self.condition {
+            WatchCondition::Changed => {
+                let changed = match (&self.last_value, &value) {
+                    (None, None) => false,
+                    (Some(last), Some(current)) => !values_equal(last.as_ref(), current.as_ref(), inspector)?,
+                    _ => true,
+                };
+                self.last_value = value;
+                Ok(changed)
+            }
+            WatchCondition::Compare { op, value: target } => {
+                let held = match &value {
+                    Some(current) => compare(current.as_ref(), *op, target, inspector)?,
+                    None => false,
+                };
+                let fired = held && !self.last_held;
+                self.last_held = held;
+                Ok(fired)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Watchpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Watchpoint")
+            .field("memory_id", &self.memory_id)
+            .field("address", &self.address)
+            .field("condition", &self.condition)
+            .finish()
+    }
+}
+
+/// Why execution stopped
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopReason {
+    Breakpoint { id: usize, cycle: u64, component_id: ComponentId },
+    Watchpoint { id: usize, cycle: u64, memory_id: ComponentId, address: String, value: String },
+}
+
+impl fmt::Display for StopReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopReason::Breakpoint { id, cycle, component_id } => {
+                write!(f, "Breakpoint {} hit at cycle {}: {}", id, cycle, component_id)
+            }
+            StopReason::Watchpoint { id, cycle, memory_id, address, value } => {
+                write!(f, "Watchpoint {} hit at cycle {}: {}[{}] = {}", id, cycle, memory_id, address, value)
+            }
+        }
+    }
+}
+
+/// Numeric view of primitive values
+pub(crate) fn as_number(value: &dyn Any) -> Option<f64> {
+    if let Some(v) = value.downcast_ref::<i32>() { return Some(*v as f64); }
+    if let Some(v) = value.downcast_ref::<i64>() { return Some(*v as f64); }
+    if let Some(v) = value.downcast_ref::<u32>() { return Some(*v as f64); }
+    if let Some(v) = value.downcast_ref::<u64>() { return Some(*v as f64); }
+    if let Some(v) = value.downcast_ref::<usize>() { return Some(*v as f64); }
+    if let Some(v) = value.downcast_ref::<f32>() { return Some(*v as f64); }
+    value.downcast_ref::<f64>().copied()
+}
+
+fn values_equal(a: &dyn Any, b: &dyn Any, inspector: &ValueInspector) -> Result<bool, String> {
+    if let Some(equal) = inspector.equals(a, b) {
+        return Ok(equal);
+    }
+    match (inspector.format(a), inspector.format(b)) {
+        (Some(a), Some(b)) => Ok(a == b),
+        _ => Err("Cannot compare values of an unregistered type; register it with the ValueInspector".to_string()),
+    }
+}
+
+fn compare(value: &dyn Any, op: CompareOp, target: &str, inspector: &ValueInspector) -> Result<bool, String> {
+    if let (Some(current), Ok(target)) = (as_number(value), target.parse::<f64>()) {
+        return Ok(current.partial_cmp(&target).is_some_and(|ordering| op.holds(ordering)));
+    }
+    let formatted = inspector.format(value)
+        .ok_or_else(|| "Cannot compare values of an unregistered type; register it with the ValueInspector".to_string())?;
+    match op {
+        CompareOp::Eq => Ok(formatted == target),
+        CompareOp::Ne => Ok(formatted != target),
+        _ => Err(format!("'{}' needs a numeric value, got {}", op.as_str(), formatted)),
+    }
+}
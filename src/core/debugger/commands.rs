@@ -0,0 +1,157 @@
+use crate::core::debugger::breakpoint::{CompareOp, StopReason, WatchCondition};
+use crate::core::debugger::session::Debugger;
+use std::fmt::Write;
+
+/// Command reference printed by `help`
+pub const HELP: &str = "\
+Commands:
+  step | s                         execute one cycle
+  run | r <N>                      execute N cycles, stopping at breakpoints
+  continue | c [MAX]               run until a breakpoint or watchpoint (at most MAX cycles, default 1000000)
+  break | b <component> [port]     stop when the component is evaluated (with an event on port, if given)
+  watch | w <memory> <address>     stop when the value at the address changes
+  watch | w <memory> <address> <op> <value>
+                                   stop when the value starts meeting op (== != < <= > >=)
+  delete | d <id>                  remove a breakpoint or watchpoint
+  info | i                         list breakpoints and watchpoints
+  components | ls                  list components
+  print | p <component>[.<port>]   show the events of a component in the current cycle
+  print | p <memory> [address]     show memory contents
+  back <N>                         rewind N cycles (needs time travel)
+  goto <cycle>                     jump to a cycle (needs time travel to go back)
+  help | h                         show this help
+  quit | q                         exit";
+
+/// Default cycle limit for `continue`
+const DEFAULT_CONTINUE_LIMIT: u64 = 1_000_000;
+
+impl Debugger {
+    /// Execute one command line and return its output
+    ///
+    /// `quit` is left to the caller.
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(String::new());
+        };
+        match command {
+            "step" | "s" => {
+                let stops = self.step()?;
+                Ok(self.describe_stop(&stops))
+            }
+            "run" | "r" => {
+                let cycles = parse_number(args.first().copied(), "cycle count")?;
+                let stops = self.run(cycles)?;
+                Ok(self.describe_stop(&stops))
+            }
+            "continue" | "c" => {
+                let limit = match args.first() {
+                    Some(limit) => parse_number(Some(limit), "cycle limit")?,
+                    None => DEFAULT_CONTINUE_LIMIT,
+                };
+                let stops = self.run(limit)?;
+                let mut text = self.describe_stop(&stops);
+                if stops.is_empty() {
+                    let _ = writeln!(text, "No breakpoint hit within {} cycles", limit);
+                }
+                Ok(text)
+            }
+            "break" | "b" => {
+                let name = args.first().ok_or("Usage: break <component> [port]")?;
+                let component_id = self.resolve(name)?;
+                let id = self.add_breakpoint(component_id, args.get(1).copied())?;
+                Ok(format!("Breakpoint {} on {}\n", id, args.join(" ")))
+            }
+            "watch" | "w" => {
+                let (name, address) = match args {
+                    [name, address, ..] => (*name, *address),
+                    _ => return Err("Usage: watch <memory> <address> [<op> <value>]".to_string()),
+                };
+                let condition = match &args[2..] {
+                    [] => WatchCondition::Changed,
+                    [op, value @ ..] if !value.is_empty() => WatchCondition::Compare {
+                        op: CompareOp::parse(op)?,
+                        value: value.join(" "),
+                    },
+                    _ => return Err("Usage: watch <memory> <address> [<op> <value>]".to_string()),
+                };
+                let memory_id = self.resolve(name)?;
+                let description = format!("{}[{}] {}", name, address, condition);
+                let id = self.add_watchpoint(memory_id, address, condition)?;
+                Ok(format!("Watchpoint {} on {}\n", id, description))
+            }
+            "delete" | "d" => {
+                let id = parse_number(args.first().copied(), "id")?;
+                self.delete(id as usize)?;
+                Ok(format!("Deleted {}\n", id))
+            }
+            "info" | "i" => {
+                let mut text = String::new();
+                for (id, breakpoint) in self.breakpoints() {
+                    let port = breakpoint.port.as_deref().map(|port| format!(".{}", port)).unwrap_or_default();
+                    let _ = writeln!(text, "  {} break {}{}", id, breakpoint.component_id, port);
+                }
+                for (id, watchpoint) in self.watchpoints() {
+                    let _ = writeln!(text, "  {} watch {}[{}] {}", id, watchpoint.memory_id, watchpoint.address, watchpoint.condition);
+                }
+                if text.is_empty() {
+                    text.push_str("  No breakpoints or watchpoints\n");
+                }
+                Ok(text)
+            }
+            "components" | "ls" => {
+                let mut text = String::new();
+                for id in self.engine().processing_component_ids() {
+                    let _ = writeln!(text, "  {} (processing)", id);
+                }
+                for id in self.engine().memory_component_ids() {
+                    let _ = writeln!(text, "  {} (memory)", id);
+                }
+                Ok(text)
+            }
+            "print" | "p" => {
+                let target = args.first().ok_or("Usage: print <component>[.<port>] | print <memory> [address]")?;
+                let (name, port) = match target.split_once('.') {
+                    Some((name, port)) => (name, Some(port)),
+                    None => (*target, None),
+                };
+                let component_id = self.resolve(name)?;
+                if self.engine().processor_module(&component_id).is_some() {
+                    Ok(format!("{} at cycle {}:\n{}", target, self.engine().current_cycle(), self.print_component(&component_id, port)))
+                } else {
+                    let address = args.get(1).copied().or(port);
+                    Ok(format!("{} at cycle {}:\n{}", name, self.engine().current_cycle(), self.print_memory(&component_id, address)?))
+                }
+            }
+            "back" => {
+                let cycles = match args.first() {
+                    Some(cycles) => parse_number(Some(cycles), "cycle count")?,
+                    None => 1,
+                };
+                let target = self.engine().current_cycle().saturating_sub(cycles);
+                self.jump_to_cycle(target)?;
+                Ok(format!("Cycle {}\n", self.engine().current_cycle()))
+            }
+            "goto" => {
+                let cycle = parse_number(args.first().copied(), "cycle")?;
+                self.jump_to_cycle(cycle)?;
+                Ok(format!("Cycle {}\n", self.engine().current_cycle()))
+            }
+            "help" | "h" => Ok(format!("{}\n", HELP)),
+            _ => Err(format!("Unknown command '{}' (try 'help')", command)),
+        }
+    }
+
+    fn describe_stop(&self, stops: &[StopReason]) -> String {
+        let mut text = format!("Cycle {}\n", self.engine().current_cycle());
+        for stop in stops {
+            let _ = writeln!(text, "{}", stop);
+        }
+        text
+    }
+}
+
+fn parse_number(value: Option<&str>, what: &str) -> Result<u64, String> {
+    let value = value.ok_or_else(|| format!("Missing {}", what))?;
+    value.parse().map_err(|_| format!("Invalid {} '{}'", what, value))
+}
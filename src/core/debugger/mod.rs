@@ -0,0 +1,7 @@
+pub mod breakpoint;
+pub mod session;
+pub mod commands;
+
+// Re-export commonly used types
+pub use breakpoint::{Breakpoint, Watchpoint, WatchCondition, CompareOp, StopReason};
+pub use session::Debugger;
@@ -0,0 +1,229 @@
+use crate::core::debugger::breakpoint::{Breakpoint, StopReason, Watchpoint, WatchCondition};
+use crate::core::execution::CycleEngine;
+use crate::core::types::ComponentId;
+use crate::core::values::events::Event;
+use crate::core::values::inspect::ValueInspector;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Cycle-level debugger for any `CycleEngine`
+///
+/// Runs the engine cycle by cycle and stops on breakpoints and watchpoints.
+/// Values are printed through a `ValueInspector`, so custom payload and memory
+/// types need `inspector_mut().register_debug::<T>()` to show their `Debug` form.
+pub struct Debugger {
+    engine: CycleEngine,
+    inspector: ValueInspector,
+    breakpoints: BTreeMap<usize, Breakpoint>,
+    watchpoints: BTreeMap<usize, Watchpoint>,
+    next_id: usize,
+}
+
+impl Debugger {
+    /// Wrap an engine whose execution order is already built
+    pub fn new(engine: CycleEngine) -> Self {
+        Self {
+            engine,
+            inspector: ValueInspector::with_primitives(),
+            breakpoints: BTreeMap::new(),
+            watchpoints: BTreeMap::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Get the engine under debug
+    pub fn engine(&self) -> &CycleEngine {
+        &self.engine
+    }
+
+    /// Get the engine under debug mutably
+    pub fn engine_mut(&mut self) -> &mut CycleEngine {
+        &mut self.engine
+    }
+
+    /// Stop debugging and return the engine
+    pub fn into_engine(self) -> CycleEngine {
+        self.engine
+    }
+
+    /// Get the inspector used to compare and print values
+    pub fn inspector_mut(&mut self) -> &mut ValueInspector {
+        &mut self.inspector
+    }
+
+    /// Find a processing or memory component by its id string
+    pub fn resolve(&self, name: &str) -> Result<ComponentId, String> {
+        self.engine.component_ids().into_iter()
+            .find(|id| id.id() == name)
+            .cloned()
+            .ok_or_else(|| format!("Unknown component '{}'", name))
+    }
+
+    /// Stop after cycles in which a component is evaluated, optionally only when an event passes `port`
+    pub fn add_breakpoint(&mut self, component_id: ComponentId, port: Option<&str>) -> Result<usize, String> {
+        let module = self.engine.processor_module(&component_id)
+            .ok_or_else(|| format!("'{}' is not a processing component", component_id))?;
+        if let Some(port) = port {
+            if !module.has_input_port(port) && !module.has_output_port(port) {
+                return Err(format!("Component '{}' has no port '{}'", component_id, port));
+            }
+        }
+        let id = self.allocate_id();
+        self.breakpoints.insert(id, Breakpoint { component_id, port: port.map(str::to_string) });
+        Ok(id)
+    }
+
+    /// Stop when a memory address changes or meets a condition
+    pub fn add_watchpoint(&mut self, memory_id: ComponentId, address: &str, condition: WatchCondition) -> Result<usize, String> {
+        if !self.engine.memory_component_ids().contains(&&memory_id) {
+            return Err(format!("'{}' is not a memory component", memory_id));
+        }
+        let mut watchpoint = Watchpoint::new(memory_id, address, condition);
+        // Prime with the current value so only later changes fire
+        let current = self.engine.read_memory_any(&watchpoint.memory_id, address);
+        watchpoint.check(current, &self.inspector)?;
+        let id = self.allocate_id();
+        self.watchpoints.insert(id, watchpoint);
+        Ok(id)
+    }
+
+    /// Remove a breakpoint or watchpoint
+    pub fn delete(&mut self, id: usize) -> Result<(), String> {
+        if self.breakpoints.remove(&id).is_some() || self.watchpoints.remove(&id).is_some() {
+            Ok(())
+        } else {
+            Err(format!("No breakpoint or watchpoint {}", id))
+        }
+    }
+
+    /// Get all breakpoints by id
+    pub fn breakpoints(&self) -> &BTreeMap<usize, Breakpoint> {
+        &self.breakpoints
+    }
+
+    /// Get all watchpoints by id
+    pub fn watchpoints(&self) -> &BTreeMap<usize, Watchpoint> {
+        &self.watchpoints
+    }
+
+    /// Execute one cycle and report any breakpoints or watchpoints it hit
+    pub fn step(&mut self) -> Result<Vec<StopReason>, String> {
+        self.engine.cycle()?;
+        self.check_stops()
+    }
+
+    /// Execute up to `cycles` cycles, stopping early at the first cycle with a hit
+    pub fn run(&mut self, cycles: u64) -> Result<Vec<StopReason>, String> {
+        for _ in 0..cycles {
+            let stops = self.step()?;
+            if !stops.is_empty() {
+                return Ok(stops);
+            }
+        }
+        Ok(Vec::new())
+    }
+
+    /// Rewind to an earlier cycle; needs time travel enabled on the engine
+    ///
+    /// Watchpoints are re-primed with the values at the new cycle.
+    pub fn jump_to_cycle(&mut self, cycle: u64) -> Result<(), String> {
+        self.engine.jump_to_cycle(cycle)?;
+        for watchpoint in self.watchpoints.values_mut() {
+            let current = self.engine.read_memory_any(&watchpoint.memory_id, &watchpoint.address);
+            watchpoint.check(current, &self.inspector)?;
+        }
+        Ok(())
+    }
+
+    /// Format an event payload with `Debug`, or its type name if unregistered
+    pub fn format_event(&self, event: &Event) -> String {
+        self.inspector.format(event.payload.as_any())
+            .unwrap_or_else(|| format!("<{}>", event.payload.type_name()))
+    }
+
+    /// Print the input and output events of a component in the current cycle
+    pub fn print_component(&self, component_id: &ComponentId, port: Option<&str>) -> String {
+        let mut text = String::new();
+        for (input, event) in self.engine.input_events(component_id) {
+            if port.is_none_or(|port| port == input) {
+                let _ = writeln!(text, "  in  {} = {}", input, self.format_event(event));
+            }
+        }
+        for (id, output, event) in self.engine.output_events() {
+            if id == component_id && port.is_none_or(|port| port == output) {
+                let _ = writeln!(text, "  out {} = {}", output, self.format_event(event));
+            }
+        }
+        if text.is_empty() {
+            text.push_str("  (no events this cycle)\n");
+        }
+        text
+    }
+
+    /// Print one or all addresses of a memory component
+    pub fn print_memory(&self, memory_id: &ComponentId, address: Option<&str>) -> Result<String, String> {
+        let mut text = String::new();
+        for info in self.engine.memory_addresses(memory_id)? {
+            if address.is_some_and(|address| address != info.address) {
+                continue;
+            }
+            let value = self.engine.read_memory_any(memory_id, &info.address)
+                .map(|value| self.format_value(value.as_ref(), info.type_name))
+                .unwrap_or_default();
+            let _ = writeln!(text, "  {} = {}", info.address, value);
+        }
+        if text.is_empty() {
+            text = match address {
+                Some(address) => format!("  {} is empty\n", address),
+                None => "  (empty)\n".to_string(),
+            };
+        }
+        Ok(text)
+    }
+
+    fn format_value(&self, value: &(dyn Any + Send), type_name: &str) -> String {
+        self.inspector.format(value).unwrap_or_else(|| format!("<{}>", type_name))
+    }
+
+    fn allocate_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Evaluate all breakpoints and watchpoints against the current cycle
+    fn check_stops(&mut self) -> Result<Vec<StopReason>, String> {
+        let cycle = self.engine.current_cycle();
+        let mut stops = Vec::new();
+        for (&id, breakpoint) in &self.breakpoints {
+            if !self.engine.was_evaluated(&breakpoint.component_id) {
+                continue;
+            }
+            let port_hit = breakpoint.port.as_deref().is_none_or(|expected| {
+                self.engine.input_events(&breakpoint.component_id).into_iter().any(|(port, _)| port == expected)
+                    || self.engine.output_events().into_iter()
+                        .any(|(component_id, port, _)| component_id == &breakpoint.component_id && port == expected)
+            });
+            if port_hit {
+                stops.push(StopReason::Breakpoint { id, cycle, component_id: breakpoint.component_id.clone() });
+            }
+        }
+        for (&id, watchpoint) in self.watchpoints.iter_mut() {
+            let current = self.engine.read_memory_any(&watchpoint.memory_id, &watchpoint.address);
+            let value = current.as_ref()
+                .map(|value| self.inspector.format(value.as_ref()).unwrap_or_else(|| "<value>".to_string()))
+                .unwrap_or_else(|| "<empty>".to_string());
+            if watchpoint.check(current, &self.inspector)? {
+                stops.push(StopReason::Watchpoint {
+                    id,
+                    cycle,
+                    memory_id: watchpoint.memory_id.clone(),
+                    address: watchpoint.address.clone(),
+                    value,
+                });
+            }
+        }
+        Ok(stops)
+    }
+}
@@ -0,0 +1,170 @@
+use crate::core::components::state::MemoryData;
+use crate::core::components::traits::{Cycle, MemoryComponent};
+use crate::core::components::types::PortType;
+use crate::core::entities::token::{Token, TokenId};
+use crate::core::memory::AppendLog;
+use std::collections::BTreeMap;
+
+/// Count, total and extremes of a set of durations in cycles
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LatencySummary {
+    pub count: u64,
+    pub total: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl LatencySummary {
+    /// Add a duration
+    pub fn record(&mut self, cycles: u64) {
+        self.min = if self.count == 0 { cycles } else { self.min.min(cycles) };
+        self.max = self.max.max(cycles);
+        self.count += 1;
+        self.total += cycles;
+    }
+
+    /// Mean duration, zero when empty
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.total as f64 / self.count as f64
+        }
+    }
+}
+
+/// Latency of retired tokens, end to end and per stage
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LatencyBreakdown {
+    /// Creation to retirement
+    pub end_to_end: LatencySummary,
+    /// Closed visits by stage
+    pub stages: BTreeMap<String, LatencySummary>,
+}
+
+/// Collection of retired tokens, stored at a memory address
+///
+/// Components `record` tokens that finished and write the log back; records
+/// written by several components in one cycle are merged and kept at the end
+/// of the cycle in the order the writes were applied. Recorded tokens are
+/// shared between copies, so the log stays cheap to snapshot as it grows.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TokenLog {
+    pending: Vec<Token>,
+    tokens: AppendLog<Token>,
+}
+
+impl TokenLog {
+    /// Create an empty log
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a finished token at the end of this cycle
+    pub fn record(&mut self, token: Token) {
+        self.pending.push(token);
+    }
+
+    /// Tokens recorded in earlier cycles, oldest first
+    pub fn tokens(&self) -> impl DoubleEndedIterator<Item = &Token> {
+        self.tokens.iter()
+    }
+
+    /// Number of tokens recorded in earlier cycles
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Check if no tokens were recorded in earlier cycles
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Find a recorded token by ID
+    pub fn trace(&self, id: &TokenId) -> Option<&Token> {
+        self.tokens.iter().find(|token| token.id() == id)
+    }
+
+    /// Latency breakdown over the recorded tokens, optionally of one kind only
+    pub fn breakdown(&self, kind: Option<&str>) -> LatencyBreakdown {
+        let mut breakdown = LatencyBreakdown::default();
+        for token in self.tokens.iter().filter(|token| kind.is_none_or(|kind| token.kind() == kind)) {
+            if let Some(latency) = token.latency() {
+                breakdown.end_to_end.record(latency);
+            }
+            for visit in token.visits() {
+                if let Some(duration) = visit.duration() {
+                    breakdown.stages.entry(visit.stage.clone()).or_default().record(duration);
+                }
+            }
+        }
+        breakdown
+    }
+
+    /// Per-token traces with a header, one line per visit
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("token,kind,stage,component,entered,left\n");
+        for token in self.tokens.iter() {
+            for visit in token.visits() {
+                let left = visit.left.map(|left| left.to_string()).unwrap_or_default();
+                csv.push_str(&format!("{},{},{},{},{},{}\n", token.id(), token.kind(), visit.stage, visit.component, visit.entered, left));
+            }
+        }
+        csv
+    }
+}
+
+impl MemoryData for TokenLog {
+    fn merge(&mut self, other: Self) {
+        self.pending.extend(other.pending);
+    }
+}
+
+impl Cycle for TokenLog {
+    type Output = usize;
+
+    fn cycle(&mut self) -> Option<Self::Output> {
+        self.tokens.append(std::mem::take(&mut self.pending));
+        Some(self.tokens.len())
+    }
+}
+
+impl MemoryComponent for TokenLog {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("input".to_string(), PortType::Input),
+            ("output".to_string(), PortType::Output),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ComponentId;
+
+    #[test]
+    fn test_breakdown_and_csv() {
+        let kitchen = ComponentId::new("kitchen1".to_string(), "Kitchen".to_string());
+        let snapshot = TokenLog::new();
+        let mut log = snapshot.clone();
+        for (seq, wait) in [(0, 2), (1, 6)] {
+            let mut token = Token::new("burger", &kitchen, 1, seq);
+            token.enter_stage(&kitchen, "wait_meat", 1);
+            token.leave(&kitchen, 1 + wait).unwrap();
+            token.retire(2 + wait);
+            // Each writer records on its own copy of the snapshot
+            let mut copy = snapshot.clone();
+            copy.record(token);
+            log.merge(copy);
+        }
+        assert!(log.is_empty());
+        log.cycle();
+
+        let breakdown = log.breakdown(Some("burger"));
+        assert_eq!(breakdown.stages["wait_meat"], LatencySummary { count: 2, total: 8, min: 2, max: 6 });
+        assert_eq!(breakdown.end_to_end.mean(), 5.0);
+        assert!(log.breakdown(Some("fries")).stages.is_empty());
+        assert_eq!(log.to_csv().lines().nth(2), Some("kitchen1@1.1,burger,wait_meat,kitchen1,1,7"));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod token;
+pub mod log;
+
+// Re-export commonly used types
+pub use token::{Token, TokenFactory, TokenId, AttributeValue, Visit};
+pub use log::{TokenLog, LatencyBreakdown, LatencySummary};
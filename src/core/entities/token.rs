@@ -0,0 +1,269 @@
+use crate::core::components::state::MemoryData;
+use crate::core::types::ComponentId;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Unique token ID: the creating component, the cycle and a sequence number within that cycle
+///
+/// IDs only depend on where and when tokens are created, so they are the same
+/// in every run and concurrency mode.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TokenId {
+    pub origin: String,
+    pub cycle: u64,
+    pub seq: u32,
+}
+
+impl fmt::Display for TokenId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}.{}", self.origin, self.cycle, self.seq)
+    }
+}
+
+/// Value of a token attribute
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue {
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Bool(bool),
+}
+
+impl From<i64> for AttributeValue {
+    fn from(value: i64) -> Self {
+        AttributeValue::Int(value)
+    }
+}
+
+impl From<f64> for AttributeValue {
+    fn from(value: f64) -> Self {
+        AttributeValue::Float(value)
+    }
+}
+
+impl From<&str> for AttributeValue {
+    fn from(value: &str) -> Self {
+        AttributeValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for AttributeValue {
+    fn from(value: String) -> Self {
+        AttributeValue::Text(value)
+    }
+}
+
+impl From<bool> for AttributeValue {
+    fn from(value: bool) -> Self {
+        AttributeValue::Bool(value)
+    }
+}
+
+/// Stay of a token at a component
+#[derive(Debug, Clone, PartialEq)]
+pub struct Visit {
+    pub component: String,
+    /// Label the stay is reported under, the component's type name unless given
+    pub stage: String,
+    pub entered: u64,
+    /// `None` while the token is still at the component
+    pub left: Option<u64>,
+}
+
+impl Visit {
+    /// Cycles spent at the component, if it has left
+    pub fn duration(&self) -> Option<u64> {
+        self.left.map(|left| left - self.entered)
+    }
+}
+
+/// Entity flowing through ports and queues, recording where it has been
+///
+/// Components carry tokens as event payloads or memory values (a
+/// `Fifo<Token>`, a `Resource<Token>`, ...) and stamp them with `enter` and
+/// `leave` as they pass. Retired tokens are collected in a `TokenLog`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    id: TokenId,
+    kind: String,
+    created: u64,
+    retired: Option<u64>,
+    attributes: BTreeMap<String, AttributeValue>,
+    visits: Vec<Visit>,
+}
+
+impl Token {
+    /// Create the `seq`-th token of `kind` made by `origin` in `cycle`
+    ///
+    /// Components create tokens through `EvaluationContext::tokens`, which
+    /// numbers them so IDs never repeat.
+    pub(crate) fn new(kind: &str, origin: &ComponentId, cycle: u64, seq: u32) -> Self {
+        Self {
+            id: TokenId { origin: origin.id().to_string(), cycle, seq },
+            kind: kind.to_string(),
+            created: cycle,
+            retired: None,
+            attributes: BTreeMap::new(),
+            visits: Vec::new(),
+        }
+    }
+
+    /// Get the token's ID
+    pub fn id(&self) -> &TokenId {
+        &self.id
+    }
+
+    /// Kind of entity, e.g. "burger"
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Cycle the token was created in
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /// Cycle the token was retired in
+    pub fn retired(&self) -> Option<u64> {
+        self.retired
+    }
+
+    /// Cycles from creation to retirement
+    pub fn latency(&self) -> Option<u64> {
+        self.retired.map(|retired| retired - self.created)
+    }
+
+    /// Set an attribute
+    pub fn set(&mut self, name: &str, value: impl Into<AttributeValue>) {
+        self.attributes.insert(name.to_string(), value.into());
+    }
+
+    /// Get an attribute
+    pub fn get(&self, name: &str) -> Option<&AttributeValue> {
+        self.attributes.get(name)
+    }
+
+    /// Attributes by name
+    pub fn attributes(&self) -> &BTreeMap<String, AttributeValue> {
+        &self.attributes
+    }
+
+    /// Record arriving at `component`, reported under its type name without the module path
+    pub fn enter(&mut self, component: &ComponentId, cycle: u64) {
+        let stage = component.module_type().rsplit("::").next().unwrap_or_default();
+        self.enter_stage(component, stage, cycle);
+    }
+
+    /// Record arriving at `component`, reported under `stage`
+    pub fn enter_stage(&mut self, component: &ComponentId, stage: &str, cycle: u64) {
+        self.visits.push(Visit {
+            component: component.id().to_string(),
+            stage: stage.to_string(),
+            entered: cycle,
+            left: None,
+        });
+    }
+
+    /// Record leaving `component`, closing its latest open visit
+    pub fn leave(&mut self, component: &ComponentId, cycle: u64) -> Result<(), String> {
+        let visit = self.visits.iter_mut().rev()
+            .find(|visit| visit.left.is_none() && visit.component == component.id())
+            .ok_or_else(|| format!("Token {} has not entered component '{}'", self.id, component))?;
+        visit.left = Some(cycle);
+        Ok(())
+    }
+
+    /// Close every open visit and enter `component` under `stage`
+    pub fn move_to(&mut self, component: &ComponentId, stage: &str, cycle: u64) {
+        self.close_visits(cycle);
+        self.enter_stage(component, stage, cycle);
+    }
+
+    fn close_visits(&mut self, cycle: u64) {
+        for visit in self.visits.iter_mut().filter(|visit| visit.left.is_none()) {
+            visit.left = Some(cycle);
+        }
+    }
+
+    /// Mark the token as finished, closing any open visits
+    pub fn retire(&mut self, cycle: u64) {
+        self.close_visits(cycle);
+        self.retired = Some(cycle);
+    }
+
+    /// Visits in the order they started
+    pub fn visits(&self) -> &[Visit] {
+        &self.visits
+    }
+
+    /// Total cycles spent in visits reported under `stage`
+    pub fn time_in(&self, stage: &str) -> u64 {
+        self.visits.iter().filter(|visit| visit.stage == stage).filter_map(Visit::duration).sum()
+    }
+}
+
+impl MemoryData for Token {}
+
+/// Creates the tokens of one component in one cycle with consecutive sequence numbers
+pub struct TokenFactory<'a> {
+    origin: &'a ComponentId,
+    cycle: u64,
+    next_seq: u32,
+}
+
+impl<'a> TokenFactory<'a> {
+    pub(crate) fn new(origin: &'a ComponentId, cycle: u64) -> Self {
+        Self { origin, cycle, next_seq: 0 }
+    }
+
+    /// Create a token of `kind` with the next unused ID
+    pub fn create(&mut self, kind: &str) -> Token {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        Token::new(kind, self.origin, self.cycle, seq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_lifecycle() -> Result<(), String> {
+        let kitchen = ComponentId::new("kitchen1".to_string(), "Kitchen".to_string());
+        let counter = ComponentId::new("counter2".to_string(), "Counter".to_string());
+        let mut burger = Token::new("burger", &kitchen, 3, 1);
+        burger.set("order", 42i64);
+        burger.enter_stage(&kitchen, "wait_bread", 3);
+        burger.move_to(&kitchen, "assembly", 5);
+        burger.leave(&kitchen, 9)?;
+        burger.enter(&counter, 9);
+        assert!(burger.leave(&kitchen, 10).is_err());
+        burger.retire(12);
+
+        assert_eq!(burger.id().to_string(), "kitchen1@3.1");
+        assert_eq!(burger.get("order"), Some(&AttributeValue::Int(42)));
+        assert_eq!((burger.time_in("wait_bread"), burger.time_in("assembly"), burger.time_in("Counter")), (2, 4, 3));
+        assert_eq!(burger.latency(), Some(9));
+        Ok(())
+    }
+
+    #[test]
+    fn test_factory_numbers_tokens() {
+        let kitchen = ComponentId::new("kitchen1".to_string(), "Kitchen".to_string());
+        let mut factory = TokenFactory::new(&kitchen, 4);
+        let ids: Vec<String> = (0..3).map(|_| factory.create("burger").id().to_string()).collect();
+        assert_eq!(ids, vec!["kitchen1@4.0", "kitchen1@4.1", "kitchen1@4.2"]);
+    }
+
+    #[test]
+    fn test_enter_uses_type_name_of_builder_components() {
+        let mut sim = crate::core::builder::simulation_builder::Simulation::new();
+        let sink = sim.add_component(crate::library::Sink);
+        let mut token = Token::new("burger", &sink, 1, 0);
+        token.enter(&sink, 1);
+        token.retire(4);
+        assert_eq!(token.visits()[0].stage, "Sink");
+        assert_eq!(token.time_in("Sink"), 3);
+    }
+}
@@ -0,0 +1,77 @@
+/// Clock domain of a component
+///
+/// A component in a domain is evaluated, and a memory component cycled and
+/// snapshotted, only in cycles where `cycle % period == phase`. Components
+/// without a domain tick every cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClockDomain {
+    pub period: u64,
+    pub phase: u64,
+}
+
+impl ClockDomain {
+    /// Domain ticking every `period` cycles, in cycles that are multiples of the period
+    pub fn every(period: u64) -> Self {
+        Self { period, phase: 0 }
+    }
+
+    /// Shift the ticks by `phase` cycles
+    pub fn with_phase(mut self, phase: u64) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Check the period is non-zero and the phase lies within it
+    pub fn validate(&self) -> Result<(), String> {
+        if self.period == 0 {
+            return Err("Clock domain period must be at least 1".to_string());
+        }
+        if self.phase >= self.period {
+            return Err(format!("Clock domain phase {} must be less than the period {}", self.phase, self.period));
+        }
+        Ok(())
+    }
+
+    /// Check if the domain ticks in `cycle`
+    pub fn ticks_at(&self, cycle: u64) -> bool {
+        cycle % self.period == self.phase
+    }
+
+    /// First cycle at or after `cycle` in which the domain ticks
+    pub fn next_tick(&self, cycle: u64) -> u64 {
+        cycle + (self.phase + self.period - cycle % self.period) % self.period
+    }
+
+    /// Check if the domain ticks every cycle
+    pub fn is_base_rate(&self) -> bool {
+        self.period == 1
+    }
+}
+
+impl Default for ClockDomain {
+    fn default() -> Self {
+        Self::every(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticks_and_next_tick() {
+        let domain = ClockDomain::every(5).with_phase(2);
+        let ticks: Vec<u64> = (1..=13).filter(|cycle| domain.ticks_at(*cycle)).collect();
+        assert_eq!(ticks, vec![2, 7, 12]);
+        assert_eq!(domain.next_tick(3), 7);
+        assert_eq!(domain.next_tick(7), 7);
+        assert!(ClockDomain::default().ticks_at(3));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(ClockDomain::every(0).validate().is_err());
+        assert!(ClockDomain::every(4).with_phase(4).validate().is_err());
+        assert!(ClockDomain::every(4).with_phase(3).validate().is_ok());
+    }
+}
@@ -29,6 +29,10 @@ pub struct SimulationConfig {
     /// The size of the thread pool for parallel execution
     /// Only relevant when concurrency_mode is Rayon
     pub thread_pool_size: Option<usize>,
+    /// Master seed for the per-component random streams
+    pub seed: u64,
+    /// Number of warm-up cycles after which collected statistics are reset
+    pub warmup_cycles: u64,
 }
 
 impl SimulationConfig {
@@ -39,6 +43,8 @@ impl SimulationConfig {
         Self {
             concurrency_mode: ConcurrencyMode::default(),
             thread_pool_size: None,
+            seed: 0,
+            warmup_cycles: 0,
         }
     }
     
@@ -68,6 +74,30 @@ impl SimulationConfig {
         self.thread_pool_size = Some(size);
         self
     }
+    
+    /// Set the master seed for the per-component random streams
+    /// 
+    /// # Arguments
+    /// * `seed` - The master seed; each component's stream is derived from it and the component id
+    /// 
+    /// # Returns
+    /// A new configuration with the specified seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+    
+    /// Set the warm-up period
+    /// 
+    /// # Arguments
+    /// * `cycles` - Number of initial cycles whose statistics are discarded
+    /// 
+    /// # Returns
+    /// A new configuration with the specified warm-up period
+    pub fn with_warmup_cycles(mut self, cycles: u64) -> Self {
+        self.warmup_cycles = cycles;
+        self
+    }
 }
 
 impl Default for SimulationConfig {
@@ -85,6 +115,7 @@ mod tests {
         let config = SimulationConfig::default();
         assert_eq!(config.concurrency_mode, ConcurrencyMode::Sequential);
         assert_eq!(config.thread_pool_size, None);
+        assert_eq!(config.seed, 0);
     }
 
     #[test]
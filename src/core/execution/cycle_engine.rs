@@ -1,15 +1,30 @@
 use crate::core::builder::simulation_builder::ComponentInstance;
-use crate::core::types::ComponentId;
-use crate::core::execution::execution_order::{ExecutionOrderBuilder, Stage};
+use crate::core::builder::diagnostics::DiagnosticsReport;
+use crate::core::types::{ComponentId, InputPort, MemoryPort, OutputPort};
+use crate::core::execution::execution_order::{ExecutionOrderBuilder, Stage, SubLevel};
 use crate::core::execution::config::{SimulationConfig, ConcurrencyMode};
-use crate::core::components::module::{EvaluationContext, MemoryModuleTrait};
+use crate::core::components::module::{ComponentModule, EvaluationContext, MemoryModuleTrait};
+use crate::core::components::state::MemoryData;
+use crate::core::components::traits::{Component, MemoryComponent};
 use crate::core::values::implementations::{EventInputMap, EventOutputMap};
 use crate::core::values::events::Event;
 use crate::core::values::traits::EventOutputs;
 use crate::core::memory::proxy::{MemoryProxy, OwnedMemoryProxy};
 use crate::core::memory::MemoryWrite;
-use std::collections::HashMap;
+use crate::core::stimulus::StimulusSource;
+use crate::core::probes::{Probe, ProbeTarget, ProbeRecorder, TimeSeries};
+use crate::core::stats::{StatsRecorder, StatsRegistry, StatsReport};
+use crate::core::components::module::MemoryStats;
+use crate::core::random::{ComponentRng, RngCheckpoint};
+use crate::core::entities::TokenFactory;
+use crate::core::replay::{CycleRecord, ExternalInputs, InputLog, StimulusRecord, ValueCodec};
+use crate::core::execution::profiler::{Profiler, ProfileKind, ProfileReport, TaskTiming};
+use crate::core::execution::history::{EngineSnapshot, History};
+use crate::core::execution::clock::ClockDomain;
+use crate::core::verification::monitor::{Monitor, MonitorReport, MonitorSet, OnViolation, Violation};
+use std::collections::{HashMap, HashSet};
 use std::sync::mpsc;
+use std::time::Instant;
 use rayon::prelude::*;
 
 /// Pre-computed memory component subsets for each processing component
@@ -22,6 +37,15 @@ pub struct ProcessingComponent {
     pub module: crate::core::components::module::ProcessorModule,
 }
 
+/// Address present in a memory module, as reported by engine introspection
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryAddressInfo {
+    /// Address within the memory module
+    pub address: String,
+    /// Type name of the data stored at the address
+    pub type_name: &'static str,
+}
+
 /// Input connection for O(1) lookup during input collection
 #[derive(Clone, Debug)]
 struct InputConnection {
@@ -57,6 +81,60 @@ pub struct CycleEngine {
     config: SimulationConfig,
     /// Pre-computed memory component access patterns for thread safety
     component_memory_map: ComponentMemoryMap,
+    /// Model diagnostics produced when the engine was built
+    diagnostics: DiagnosticsReport,
+    /// External stimulus sources: (component_id, input_port) -> source
+    stimuli: HashMap<(ComponentId, String), Box<dyn StimulusSource>>,
+    /// Stimulus events for the current cycle, grouped by target component
+    stimulus_buffer: HashMap<ComponentId, Vec<(String, Event)>>,
+    /// Output and memory probes with their recorded time series
+    probes: ProbeRecorder,
+    /// Statistics recorded by components during the run
+    stats: StatsRegistry,
+    /// Read/write counts per memory component
+    memory_stats: HashMap<ComponentId, MemoryStats>,
+    /// Wall-time profiler, present only while profiling is enabled
+    profiler: Option<Profiler>,
+    /// Random stream per processing component
+    rngs: HashMap<ComponentId, ComponentRng>,
+    /// Inputs logged so far, present only while recording
+    recording: Option<InputLog>,
+    /// Log being replayed instead of live inputs
+    replay: Option<InputLog>,
+    /// Random stream positions at the start of the current cycle, while recording
+    rng_cycle_start: HashMap<ComponentId, u128>,
+    /// Encoding of stimulus values for input logs
+    codec: ValueCodec,
+    /// Snapshots and inputs for stepping backwards, present only while time travel is enabled
+    history: Option<History>,
+    /// Invariants and temporal properties checked at the end of each cycle
+    monitors: MonitorSet,
+    /// State before the first cycle, restored by `reset`
+    initial_state: Option<EngineSnapshot>,
+    /// Counter for IDs of components added after the engine was built
+    next_component_index: u64,
+    /// Components skipped by the scheduler until resumed
+    suspended: HashSet<ComponentId>,
+    /// Components not evaluated in the current cycle (suspended or off their clock tick)
+    idle: HashSet<ComponentId>,
+    /// Memory state captured when each suspended memory component was suspended
+    frozen_memory: HashMap<ComponentId, Box<dyn MemoryModuleTrait>>,
+    /// Control connections: (source_id, source_port) -> components gated by that bool output
+    controls: HashMap<(ComponentId, String), Vec<ComponentId>>,
+    /// Clock domains of components that do not tick every cycle
+    clock_domains: HashMap<ComponentId, ClockDomain>,
+    /// Events waiting for their consumer's next tick: component -> input port -> latest event
+    held_inputs: HashMap<ComponentId, HashMap<String, Event>>,
+}
+
+/// Results of evaluating one processing component, merged by the main thread
+struct ComponentOutcome {
+    outputs: HashMap<(ComponentId, String), Event>,
+    stats: StatsRecorder,
+    memory_access: HashMap<ComponentId, MemoryStats>,
+    timing: Option<TaskTiming>,
+    rng: ComponentRng,
+    external: ExternalInputs,
 }
 
 impl CycleEngine {
@@ -74,6 +152,28 @@ impl CycleEngine {
             input_connections: HashMap::new(),
             config,
             component_memory_map: HashMap::new(),
+            diagnostics: DiagnosticsReport::default(),
+            stimuli: HashMap::new(),
+            stimulus_buffer: HashMap::new(),
+            probes: ProbeRecorder::new(),
+            stats: StatsRegistry::new(),
+            memory_stats: HashMap::new(),
+            profiler: None,
+            rngs: HashMap::new(),
+            recording: None,
+            replay: None,
+            rng_cycle_start: HashMap::new(),
+            codec: ValueCodec::new(),
+            history: None,
+            monitors: MonitorSet::new(),
+            initial_state: None,
+            next_component_index: 0,
+            suspended: HashSet::new(),
+            idle: HashSet::new(),
+            frozen_memory: HashMap::new(),
+            controls: HashMap::new(),
+            clock_domains: HashMap::new(),
+            held_inputs: HashMap::new(),
         }
     }
     
@@ -87,14 +187,16 @@ impl CycleEngine {
     pub fn register_component_instance(&mut self, instance: ComponentInstance) -> Result<(), String> {
         let id = instance.id.clone();
         match instance.module {
-            crate::core::components::module::ComponentModule::Processing(module) => {
+            ComponentModule::Processing(module) => {
                 let processing_comp = ProcessingComponent {
                     id: id.clone(),
                     module,
                 };
+                self.rngs.insert(id.clone(), ComponentRng::for_component(self.config.seed, &id));
                 self.processing_components.insert(id, processing_comp);
             }
-            crate::core::components::module::ComponentModule::Memory(module) => {
+            ComponentModule::Memory(module) => {
+                self.memory_stats.insert(id.clone(), MemoryStats::new());
                 self.memory_components.insert(id, module);
             }
         }
@@ -144,8 +246,396 @@ impl CycleEngine {
         Ok(())
     }
 
+    /// Place an initial value in a memory module, readable from the first cycle
+    pub(crate) fn init_memory(&mut self, memory_id: &ComponentId, address: &str, value: Box<dyn std::any::Any + Send>) -> Result<(), String> {
+        let memory = self.memory_components.get_mut(memory_id)
+            .ok_or_else(|| format!("Memory component '{}' not found", memory_id))?;
+        if !memory.init_any(address, value) {
+            return Err(format!("Initial value for '{}[{}]' does not match the memory's data type", memory_id, address));
+        }
+        Ok(())
+    }
+
+    /// Attach an external stimulus source to a component input port
+    ///
+    /// The port must exist and must not already be driven by a connection
+    /// or by another stimulus source.
+    pub fn add_stimulus(
+        &mut self,
+        target: (ComponentId, String),
+        source: Box<dyn StimulusSource>,
+    ) -> Result<(), String> {
+        let (target_id, target_port) = &target;
+        let component = self.processing_components.get(target_id)
+            .ok_or_else(|| format!("Target component '{}' not found", target_id))?;
+        if !component.module.has_input_port(target_port) {
+            return Err(format!("Component '{}' does not have input port '{}'", target_id, target_port));
+        }
+        let driven_by_connection = self.connections.values()
+            .any(|targets| targets.iter().any(|(tid, tport)| tid == target_id && tport == target_port));
+        if driven_by_connection || self.stimuli.contains_key(&target) {
+            return Err(format!("Input port '{}' on component '{}' is already connected", target_port, target_id));
+        }
+        
+        self.stimuli.insert(target, source);
+        Ok(())
+    }
+
+    /// Attach a probe to an output port or memory address
+    pub fn add_probe(&mut self, probe: Probe) -> Result<(), String> {
+        match &probe.target {
+            ProbeTarget::Output { component_id, port } => {
+                let component = self.processing_components.get(component_id)
+                    .ok_or_else(|| format!("Probed component '{}' not found", component_id))?;
+                if !component.module.has_output_port(port) {
+                    return Err(format!("Component '{}' does not have output port '{}'", component_id, port));
+                }
+            }
+            ProbeTarget::Memory { memory_id, .. } => {
+                if !self.memory_components.contains_key(memory_id) {
+                    return Err(format!("Probed memory component '{}' not found", memory_id));
+                }
+            }
+        }
+        self.probes.add(probe)
+    }
+
+    /// Get the time series recorded by a probe
+    pub fn probe_series(&self, name: &str) -> Option<&TimeSeries> {
+        self.probes.series(name)
+    }
+
+    /// Get all probes and their recorded time series
+    pub fn probe_recorder(&self) -> &ProbeRecorder {
+        &self.probes
+    }
+
+    /// Get the statistics recorded by components so far
+    pub fn stats(&self) -> &StatsRegistry {
+        &self.stats
+    }
+
+    /// Summarize recorded statistics up to the end of the current cycle
+    pub fn stats_report(&self) -> StatsReport {
+        self.stats.report(self.current_cycle + 1)
+    }
+
+    /// Discard statistics collected so far; later cycles start a fresh measurement window
+    ///
+    /// Called automatically at the end of the warm-up period configured with
+    /// `SimulationConfig::with_warmup_cycles`. Probe time series are kept.
+    pub fn reset_statistics(&mut self) {
+        self.stats.reset(self.current_cycle + 1);
+        for stats in self.memory_stats.values_mut() {
+            stats.reset_total_counters();
+        }
+    }
+
+    /// Get read/write counts for a memory component
+    pub fn memory_stats(&self, memory_id: &ComponentId) -> Option<&MemoryStats> {
+        self.memory_stats.get(memory_id)
+    }
+
+    /// Start measuring wall time per component, memory, stage and sub-level
+    pub fn enable_profiling(&mut self) {
+        self.profiler.get_or_insert_with(Profiler::new);
+    }
+
+    /// Stop profiling and return the collected profile
+    pub fn disable_profiling(&mut self) -> Option<Profiler> {
+        self.profiler.take()
+    }
+
+    /// Get the profiler if profiling is enabled
+    pub fn profiler(&self) -> Option<&Profiler> {
+        self.profiler.as_ref()
+    }
+
+    /// Get a profile report sorted by total time, if profiling is enabled
+    pub fn profile_report(&self) -> Option<ProfileReport> {
+        self.profiler.as_ref().map(|profiler| profiler.report())
+    }
+
+    /// Check a monitor at the end of every cycle
+    ///
+    /// With `OnViolation::Abort`, a violation makes `cycle()` fail with the
+    /// violation report; with `OnViolation::Count` it is only recorded.
+    pub fn add_monitor<M: Monitor + 'static>(&mut self, monitor: M, action: OnViolation) -> Result<(), String> {
+        self.monitors.add(Box::new(monitor), action)
+    }
+
+    /// Set how many cycles of observed values are kept per monitor for violation reports
+    pub fn set_monitor_history_window(&mut self, cycles: usize) {
+        self.monitors.set_history_window(cycles);
+    }
+
+    /// Get the violations recorded so far
+    pub fn violations(&self) -> &[Violation] {
+        self.monitors.violations()
+    }
+
+    /// Summarize monitor checks and violations
+    pub fn monitor_report(&self) -> MonitorReport {
+        self.monitors.report()
+    }
+
+    /// Save the random stream positions of all components
+    pub fn rng_checkpoint(&self) -> RngCheckpoint {
+        RngCheckpoint {
+            master_seed: self.config.seed,
+            states: self.rngs.iter()
+                .map(|(id, rng)| (id.id().to_string(), rng.state()))
+                .collect(),
+        }
+    }
+
+    /// Restore random stream positions saved by `rng_checkpoint`
+    pub fn restore_rng_checkpoint(&mut self, checkpoint: &RngCheckpoint) -> Result<(), String> {
+        let ids: HashMap<&str, ComponentId> = self.rngs.keys().map(|id| (id.id(), id.clone())).collect();
+        let mut restored = Vec::new();
+        for (name, state) in &checkpoint.states {
+            let id = ids.get(name.as_str())
+                .ok_or_else(|| format!("RNG checkpoint refers to unknown component '{}'", name))?;
+            restored.push((id.clone(), ComponentRng::from_state(state)));
+        }
+        self.rngs.extend(restored);
+        Ok(())
+    }
+
+    /// Register a stimulus value type so it can be written to input logs
+    pub fn register_replay_type<T>(&mut self)
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        self.codec.register::<T>();
+    }
+
+    /// Start logging stimuli, random draws and external inputs
+    pub fn start_recording(&mut self) {
+        self.recording.get_or_insert_with(|| InputLog::new(self.config.seed));
+    }
+
+    /// Stop recording and return the log
+    pub fn take_recording(&mut self) -> Option<InputLog> {
+        self.rng_cycle_start.clear();
+        self.recording.take()
+    }
+
+    /// Get the log recorded so far
+    pub fn recording(&self) -> Option<&InputLog> {
+        self.recording.as_ref()
+    }
+
+    /// Replay a recorded log instead of polling stimuli and external sources
+    ///
+    /// Random streams are re-seeded from the log and repositioned at the start
+    /// of every logged cycle, so a component that draws more or fewer numbers
+    /// than in the recorded run does not shift the draws of later cycles.
+    pub fn start_replay(&mut self, log: InputLog) {
+        if log.master_seed != self.config.seed {
+            self.config.seed = log.master_seed;
+            for (id, rng) in self.rngs.iter_mut() {
+                *rng = ComponentRng::for_component(log.master_seed, id);
+            }
+        }
+        self.replay = Some(log);
+    }
+
+    /// Stop replaying and return to live inputs
+    pub fn stop_replay(&mut self) -> Option<InputLog> {
+        self.replay.take()
+    }
+
+    /// Check if a log is being replayed
+    pub fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// Keep a snapshot every `interval` cycles, at most `capacity` of them, so the run can be rewound
+    ///
+    /// The current state becomes the earliest reachable cycle. Memory is cloned
+    /// for every snapshot, so a small interval trades memory for faster jumps.
+    pub fn enable_time_travel(&mut self, interval: u64, capacity: usize) {
+        let snapshot = self.capture_snapshot();
+        self.history = Some(History::new(interval, capacity, snapshot));
+    }
+
+    /// Stop keeping history
+    pub fn disable_time_travel(&mut self) {
+        self.history = None;
+    }
+
+    /// Get the time-travel history, if enabled
+    pub fn history(&self) -> Option<&History> {
+        self.history.as_ref()
+    }
+
+    /// Rewind or advance to the end of `cycle`
+    ///
+    /// Going back restores the nearest earlier snapshot and re-executes forward.
+    /// Cycles up to `History::latest_cycle` re-execute with the stimuli and
+    /// external inputs they originally saw; later cycles run live.
+    pub fn jump_to_cycle(&mut self, cycle: u64) -> Result<(), String> {
+        let history = self.history.as_ref()
+            .ok_or_else(|| "Time travel is not enabled".to_string())?;
+        if cycle < self.current_cycle {
+            let snapshot = history.nearest_snapshot(cycle)
+                .ok_or_else(|| format!("Cycle {} is no longer in the history (earliest is {})", cycle, history.earliest_cycle()))?
+                .clone();
+            self.restore_snapshot(snapshot);
+        }
+        while self.current_cycle < cycle {
+            self.cycle()?;
+        }
+        Ok(())
+    }
+
+    /// Return to the state before the first cycle without rebuilding the model
+    ///
+    /// Memory contents, suspensions, statistics, probe samples and monitor
+    /// results go back to how they were before cycle 1, and random streams are
+    /// reseeded from the configured seed. Components, connections, the
+    /// execution order, probes and monitors themselves are kept. Stimulus
+    /// sources are rewound and polled again from cycle 1; if one of them
+    /// cannot be rewound, nothing is changed and an error is returned.
+    pub fn reset(&mut self) -> Result<(), String> {
+        let targets = self.rewindable_stimuli()?;
+        for target in targets {
+            let source = self.stimuli.get_mut(&target).expect("stimulus target exists");
+            source.rewind().map_err(|e| format!("Cannot reset stimulus of {}.{}: {}", target.0, target.1, e))?;
+        }
+        let initial = match self.initial_state.as_ref() {
+            Some(initial) => initial.clone(),
+            None => self.capture_snapshot(),
+        };
+        self.restore_snapshot(initial);
+        let seed = self.config.seed;
+        self.rngs = self.processing_components.keys()
+            .map(|id| (id.clone(), ComponentRng::for_component(seed, id)))
+            .collect();
+        self.monitors.reset();
+        self.restart_history();
+        Ok(())
+    }
+
+    /// Reset with a new master seed, e.g. for the next replication
+    pub fn reset_with_seed(&mut self, seed: u64) -> Result<(), String> {
+        self.rewindable_stimuli()?;
+        self.config.seed = seed;
+        self.reset()
+    }
+
+    /// Stimulus targets in polling order, failing if any source cannot be rewound
+    fn rewindable_stimuli(&self) -> Result<Vec<(ComponentId, String)>, String> {
+        let mut targets: Vec<(ComponentId, String)> = self.stimuli.keys().cloned().collect();
+        targets.sort();
+        if let Some((id, port)) = targets.iter().find(|target| !self.stimuli[*target].can_rewind()) {
+            return Err(format!("Cannot reset: the stimulus of {}.{} does not support rewinding", id, port));
+        }
+        Ok(targets)
+    }
+
+    /// Rewind by `cycles` cycles
+    pub fn step_back(&mut self, cycles: u64) -> Result<(), String> {
+        self.jump_to_cycle(self.current_cycle.saturating_sub(cycles))
+    }
+
+    /// Capture the state needed to resume after the current cycle
+    fn capture_snapshot(&self) -> EngineSnapshot {
+        EngineSnapshot {
+            cycle: self.current_cycle,
+            memory: self.memory_components.iter().map(|(id, module)| (id.clone(), module.clone_module())).collect(),
+            outputs: self.output_buffer.clone(),
+            rngs: self.rngs.clone(),
+            stats: self.stats.clone(),
+            memory_stats: self.memory_stats.clone(),
+            suspended: self.suspended.clone(),
+            idle: self.idle.clone(),
+            frozen_memory: self.frozen_memory.iter().map(|(id, module)| (id.clone(), module.clone_module())).collect(),
+            held_inputs: self.held_inputs.clone(),
+            monitors: self.monitors.snapshot(),
+        }
+    }
+
+    /// Return to a captured state, discarding probe samples and monitor results after it
+    fn restore_snapshot(&mut self, snapshot: EngineSnapshot) {
+        self.current_cycle = snapshot.cycle;
+        self.memory_components = snapshot.memory;
+        self.output_buffer = snapshot.outputs;
+        self.rngs = snapshot.rngs;
+        self.stats = snapshot.stats;
+        self.memory_stats = snapshot.memory_stats;
+        self.suspended = snapshot.suspended;
+        self.idle = snapshot.idle;
+        self.frozen_memory = snapshot.frozen_memory;
+        self.held_inputs = snapshot.held_inputs;
+        self.stimulus_buffer.clear();
+        self.rng_cycle_start.clear();
+        self.probes.truncate_after(snapshot.cycle);
+        self.monitors.restore(&snapshot.monitors, snapshot.cycle);
+    }
+
+    /// Get the record being written for the current cycle
+    fn recording_entry(&mut self) -> Option<&mut CycleRecord> {
+        let cycle = self.current_cycle;
+        self.recording.as_mut().map(|log| {
+            log.cycles.entry(cycle).or_insert_with(|| CycleRecord { cycle, ..CycleRecord::default() })
+        })
+    }
+
+    /// Take a component's random stream and external inputs out for evaluation
+    fn take_rng(&mut self, component_id: &ComponentId) -> (ComponentRng, ExternalInputs) {
+        let mut rng = self.rngs.remove(component_id)
+            .unwrap_or_else(|| ComponentRng::for_component(self.config.seed, component_id));
+        let cycle = self.current_cycle;
+        let mut replayed = None;
+        if let Some(record) = self.replay.as_ref().and_then(|log| log.cycle(cycle)) {
+            if let Some(&word_pos) = record.rng.get(component_id.id()) {
+                rng.seek(word_pos);
+            }
+            replayed = Some(record.external.get(component_id.id()).cloned().unwrap_or_default());
+        } else if self.replay.is_some() {
+            replayed = Some(Vec::new());
+        }
+        if let Some(inputs) = self.history.as_ref().and_then(|history| history.inputs(cycle)) {
+            replayed = Some(inputs.external.get(component_id).cloned().unwrap_or_default());
+        }
+        if self.recording.is_some() {
+            self.rng_cycle_start.insert(component_id.clone(), rng.word_pos());
+        }
+        let recording = self.recording.is_some()
+            || self.history.as_ref().is_some_and(|history| history.is_new_cycle(cycle));
+        (rng, ExternalInputs::with_log(replayed, recording))
+    }
+
+    /// Put a component's random stream back after evaluation, logging what it consumed
+    fn return_rng(&mut self, component_id: &ComponentId, rng: ComponentRng, mut external: ExternalInputs) {
+        let captured = external.take_recorded();
+        if let Some(start) = self.rng_cycle_start.remove(component_id) {
+            let word_pos = rng.word_pos();
+            if let Some(record) = self.recording_entry() {
+                if word_pos != start {
+                    record.rng.insert(component_id.id().to_string(), start);
+                }
+                if !captured.is_empty() {
+                    record.external.insert(component_id.id().to_string(), captured.clone());
+                }
+            }
+        }
+        if !captured.is_empty() {
+            let cycle = self.current_cycle;
+            if let Some(history) = self.history.as_mut() {
+                history.record_external(cycle, component_id, captured);
+            }
+        }
+        self.rngs.insert(component_id.clone(), rng);
+    }
+
     /// Execute one simulation cycle
     pub fn cycle(&mut self) -> Result<(), String> {
+        if self.initial_state.is_none() && self.current_cycle == 0 {
+            self.initial_state = Some(self.capture_snapshot());
+        }
         match self.config.concurrency_mode {
             ConcurrencyMode::Sequential => self.cycle_sequential(),
             ConcurrencyMode::Rayon => {
@@ -157,52 +647,204 @@ impl CycleEngine {
         }
     }
     
-    /// Execute one simulation cycle sequentially
-    fn cycle_sequential(&mut self) -> Result<(), String> {
+    /// Advance the cycle counter and prepare per-cycle buffers
+    fn begin_cycle(&mut self) -> Result<(), String> {
         self.current_cycle += 1;
-
+        self.idle = self.suspended.iter().chain(self.clock_domains.keys())
+            .filter(|id| !self.is_active(id))
+            .cloned()
+            .collect();
+        
         // Clear output buffer from previous cycle to prevent unbounded growth
         self.output_buffer.clear();
+        for stats in self.memory_stats.values_mut() {
+            stats.reset_cycle_counters();
+        }
+        
+        self.stimulus_buffer.clear();
+        let cycle = self.current_cycle;
+        let stimuli = if let Some(inputs) = self.history.as_ref().and_then(|history| history.inputs(cycle)) {
+            // Re-executing a rewound cycle: feed the stimuli it saw originally
+            inputs.stimuli.clone()
+        } else if let Some(log) = self.replay.as_ref() {
+            // Inject logged stimuli instead of polling the sources
+            let ids: HashMap<&str, &ComponentId> = self.processing_components.keys().map(|id| (id.id(), id)).collect();
+            let mut stimuli = Vec::new();
+            for stimulus in log.cycle(cycle).map(|record| record.stimuli.as_slice()).unwrap_or_default() {
+                let target_id = ids.get(stimulus.component.as_str())
+                    .ok_or_else(|| format!("Input log refers to unknown component '{}'", stimulus.component))?;
+                let value = self.codec.decode(&stimulus.type_name, stimulus.value.clone())?;
+                stimuli.push(((*target_id).clone(), stimulus.port.clone(), value));
+            }
+            stimuli
+        } else {
+            // Poll external stimulus sources in a deterministic order
+            let mut targets: Vec<(ComponentId, String)> = self.stimuli.keys().cloned().collect();
+            targets.sort();
+            let mut stimuli = Vec::new();
+            for (target_id, target_port) in targets {
+                let source = self.stimuli.get_mut(&(target_id.clone(), target_port.clone())).expect("stimulus target exists");
+                if let Some(value) = source.next_value(cycle)? {
+                    stimuli.push((target_id, target_port, value));
+                }
+            }
+            stimuli
+        };
+        
+        if self.recording.is_some() {
+            let mut records = Vec::new();
+            for (target_id, target_port, value) in &stimuli {
+                records.push(StimulusRecord {
+                    component: target_id.id().to_string(),
+                    port: target_port.clone(),
+                    type_name: value.type_name().to_string(),
+                    value: self.codec.encode(value)?,
+                });
+            }
+            if let Some(log) = self.recording.as_mut() {
+                log.cycles.remove(&cycle);
+            }
+            if let Some(entry) = self.recording_entry() {
+                entry.stimuli = records;
+            }
+        }
+        if let Some(history) = self.history.as_mut() {
+            history.record_stimuli(cycle, stimuli.clone());
+        }
+        for (target_id, target_port, value) in stimuli {
+            self.stimulus_buffer
+                .entry(target_id)
+                .or_default()
+                .push((target_port, Event::from_typed_value(cycle, value)));
+        }
+        
+        Ok(())
+    }
+
+    /// Finish a cycle once processing and memory updates are complete
+    fn end_cycle(&mut self) -> Result<(), String> {
+        let cycle = self.current_cycle;
+        self.hold_cross_domain_events();
+        self.apply_controls()?;
+        if let Some(log) = self.recording.as_mut() {
+            if log.cycles.get(&cycle).is_some_and(|record| record.is_empty()) {
+                log.cycles.remove(&cycle);
+            }
+        }
+        self.sample_probes();
+        // The cycle is committed either way; an aborting monitor's error is returned after the bookkeeping
+        let mut monitor_result = Ok(());
+        if !self.monitors.is_empty() {
+            let mut monitors = std::mem::take(&mut self.monitors);
+            monitor_result = monitors.check(self);
+            self.monitors = monitors;
+        }
+        if self.config.warmup_cycles > 0 && self.current_cycle == self.config.warmup_cycles {
+            self.reset_statistics();
+        }
+        if self.history.as_ref().is_some_and(|history| history.wants_snapshot(cycle)) {
+            let snapshot = self.capture_snapshot();
+            if let Some(history) = self.history.as_mut() {
+                history.push_snapshot(snapshot);
+            }
+        }
+        if let Some(history) = self.history.as_mut() {
+            history.finish_cycle(cycle);
+        }
+        monitor_result
+    }
+
+    /// Merge a component's recorded statistics and memory access counts
+    fn merge_component_stats(&mut self, stats: StatsRecorder, memory_access: HashMap<ComponentId, MemoryStats>) {
+        if !stats.is_empty() {
+            self.stats.apply(stats, self.current_cycle);
+        }
+        for (memory_id, access) in memory_access {
+            self.memory_stats.entry(memory_id).or_default().merge_counts(&access);
+        }
+    }
+
+    /// Sample all probes due in the current cycle
+    fn sample_probes(&mut self) {
+        if self.probes.is_empty() {
+            return;
+        }
+        
+        let cycle = self.current_cycle;
+        let mut samples = Vec::new();
+        for probe in self.probes.probes() {
+            if !probe.samples_at(cycle) {
+                continue;
+            }
+            let value = match &probe.target {
+                ProbeTarget::Output { component_id, port } => self.output_buffer
+                    .get(&(component_id.clone(), port.clone()))
+                    .and_then(|event| probe.project(event.payload.as_any())),
+                ProbeTarget::Memory { memory_id, address } => self.read_memory_any(memory_id, address)
+                    .and_then(|data| probe.project(data.as_ref())),
+            };
+            if let Some(value) = value {
+                samples.push((probe.name.clone(), value));
+            }
+        }
+        for (name, value) in samples {
+            self.probes.record(&name, cycle, value);
+        }
+    }
+
+    /// Execute one simulation cycle sequentially
+    fn cycle_sequential(&mut self) -> Result<(), String> {
+        self.begin_cycle()?;
 
         // Execute processing components in topological order (staged execution)
-        for stage in &self.execution_order.clone() {
+        for (stage_index, stage) in self.execution_order.clone().iter().enumerate() {
+            let stage_start = self.profiler.is_some().then(Instant::now);
             for component_id in stage {
-                self.execute_processing_component(component_id)?;
+                if self.is_active(component_id) {
+                    self.execute_processing_component(component_id)?;
+                }
+            }
+            if let (Some(profiler), Some(stage_start)) = (self.profiler.as_mut(), stage_start) {
+                profiler.record_since(ProfileKind::Stage, &format!("stage {}", stage_index), stage_start);
             }
         }
 
         // Update memory components
-        for component_id in self.memory_components.keys().cloned().collect::<Vec<_>>() {
+        for component_id in self.active_memory_components() {
             self.execute_memory_component(&component_id)?;
         }
 
-        Ok(())
+        self.end_cycle()
     }
 
     /// Execute one simulation cycle in parallel using rayon with channel-based memory synchronization
     /// Implements stage-parallel execution with proper error aggregation
     fn cycle_parallel_rayon(&mut self) -> Result<(), String> {
-        self.current_cycle += 1;
-        
-        // Clear output buffer from previous cycle to prevent unbounded growth
-        self.output_buffer.clear();
+        self.begin_cycle()?;
         
         // Processing phase: stage-parallel execution with channel-based memory synchronization
         // Each stage runs sequentially, but components within each stage run in parallel
-        for stage in &self.execution_order.clone() {
+        for (stage_index, stage) in self.execution_order.clone().iter().enumerate() {
+            let stage = &self.active_components(stage);
             if stage.is_empty() {
                 continue;
             }
+            let stage_start = self.profiler.is_some().then(Instant::now);
             
             // Create channel for memory writes
             let (memory_write_sender, memory_write_receiver) = mpsc::channel::<MemoryWrite>();
             
+            // Hand each component its own random stream so draws are independent of scheduling
+            let rngs: Vec<(ComponentRng, ExternalInputs)> = stage.iter().map(|id| self.take_rng(id)).collect();
+            
             // Execute all components in this stage in parallel
-            let stage_results: Vec<Result<HashMap<(ComponentId, String), Event>, String>> = stage
+            let profiling = self.profiler.is_some();
+            let stage_results: Vec<Result<ComponentOutcome, String>> = stage
                 .par_iter()
-                .map(|component_id| {
+                .zip(rngs)
+                .map(|(component_id, (rng, external))| {
                     let sender = memory_write_sender.clone();
-                    self.execute_processing_component_parallel(component_id, sender)
+                    self.execute_processing_component_parallel(component_id, rng, external, sender, profiling)
                 })
                 .collect();
             
@@ -211,12 +853,19 @@ impl CycleEngine {
             
             // Aggregate results and errors
             let mut all_outputs = HashMap::new();
+            let mut outcomes = Vec::new();
+            let mut timings = Vec::new();
             let mut errors = Vec::new();
             
             for (idx, result) in stage_results.into_iter().enumerate() {
                 match result {
-                    Ok(outputs) => {
-                        all_outputs.extend(outputs);
+                    Ok(outcome) => {
+                        self.return_rng(&stage[idx], outcome.rng, outcome.external);
+                        all_outputs.extend(outcome.outputs);
+                        outcomes.push((outcome.stats, outcome.memory_access));
+                        if let Some(timing) = outcome.timing {
+                            timings.push((stage[idx].clone(), timing));
+                        }
                     }
                     Err(error) => {
                         let component_id = &stage[idx];
@@ -231,8 +880,15 @@ impl CycleEngine {
                                   errors.len(), errors.join(", ")));
             }
             
-            // Sequential merge of outputs after successful stage completion
+            // Sequential merge of outputs and statistics after successful stage completion
             self.output_buffer.extend(all_outputs);
+            for (stats, memory_access) in outcomes {
+                self.merge_component_stats(stats, memory_access);
+            }
+            if let (Some(profiler), Some(stage_start)) = (self.profiler.as_mut(), stage_start) {
+                profiler.record_sub_level(stage_index, 0, stage_start, &timings);
+                profiler.record_since(ProfileKind::Stage, &format!("stage {}", stage_index), stage_start);
+            }
             
             // Apply memory writes sequentially in main thread
             self.apply_memory_writes(memory_write_receiver)?;
@@ -240,41 +896,46 @@ impl CycleEngine {
         
         // Memory phase: Execute memory components sequentially after parallel processing completes
         // This ensures all memory components are properly updated for the next cycle
-        for component_id in self.memory_components.keys().cloned().collect::<Vec<_>>() {
+        for component_id in self.active_memory_components() {
             self.execute_memory_component(&component_id)?;
         }
         
-        Ok(())
+        self.end_cycle()
     }
 
     /// Execute one simulation cycle in parallel using rayon with sub-level granularity
     /// This method implements the enhanced parallel execution with proper topological ordering
     /// at sub-level granularity to fix memory synchronization issues
     fn cycle_parallel_rayon_with_sub_levels(&mut self) -> Result<(), String> {
-        self.current_cycle += 1;
-        
-        // Clear output buffer from previous cycle to prevent unbounded growth
-        self.output_buffer.clear();
+        self.begin_cycle()?;
         
         // Processing phase: sub-level parallel execution with channel-based memory synchronization
         // Each stage runs sequentially, but within each stage, sub-levels run sequentially
         // while components within each sub-level run in parallel
-        for stage in &self.sub_level_execution_order.clone() {
+        for (stage_index, stage) in self.sub_level_execution_order.clone().iter().enumerate() {
+            let stage_start = self.profiler.is_some().then(Instant::now);
             // Execute each sub-level within the stage sequentially
-            for sub_level in &stage.sub_levels {
-                if sub_level.components.is_empty() {
+            for (sub_level_index, sub_level) in stage.sub_levels.iter().enumerate() {
+                let components = self.active_components(&sub_level.components);
+                if components.is_empty() {
                     continue;
                 }
+                let sub_level_start = self.profiler.is_some().then(Instant::now);
                 
                 // Create channel for memory writes
                 let (memory_write_sender, memory_write_receiver) = mpsc::channel::<MemoryWrite>();
                 
+                // Hand each component its own random stream so draws are independent of scheduling
+                let rngs: Vec<(ComponentRng, ExternalInputs)> = components.iter().map(|id| self.take_rng(id)).collect();
+                
                 // Execute all components in this sub-level in parallel
-                let sub_level_results: Vec<Result<HashMap<(ComponentId, String), Event>, String>> = sub_level.components
+                let profiling = self.profiler.is_some();
+                let sub_level_results: Vec<Result<ComponentOutcome, String>> = components
                     .par_iter()
-                    .map(|component_id| {
+                    .zip(rngs)
+                    .map(|(component_id, (rng, external))| {
                         let sender = memory_write_sender.clone();
-                        self.execute_processing_component_parallel(component_id, sender)
+                        self.execute_processing_component_parallel(component_id, rng, external, sender, profiling)
                     })
                     .collect();
                 
@@ -283,15 +944,22 @@ impl CycleEngine {
                 
                 // Aggregate results and errors
                 let mut all_outputs = HashMap::new();
+                let mut outcomes = Vec::new();
+                let mut timings = Vec::new();
                 let mut errors = Vec::new();
                 
                 for (idx, result) in sub_level_results.into_iter().enumerate() {
                     match result {
-                        Ok(outputs) => {
-                            all_outputs.extend(outputs);
+                        Ok(outcome) => {
+                            self.return_rng(&components[idx], outcome.rng, outcome.external);
+                            all_outputs.extend(outcome.outputs);
+                            outcomes.push((outcome.stats, outcome.memory_access));
+                            if let Some(timing) = outcome.timing {
+                                timings.push((components[idx].clone(), timing));
+                            }
                         }
                         Err(error) => {
-                            let component_id = &sub_level.components[idx];
+                            let component_id = &components[idx];
                             errors.push(format!("Component '{}': {}", component_id, error));
                         }
                     }
@@ -303,20 +971,31 @@ impl CycleEngine {
                                       errors.len(), errors.join(", ")));
                 }
                 
-                // Sequential merge of outputs after successful sub-level completion
+                // Sequential merge of outputs and statistics after successful sub-level completion
                 self.output_buffer.extend(all_outputs);
+                for (stats, memory_access) in outcomes {
+                    self.merge_component_stats(stats, memory_access);
+                }
                 
                 // Apply memory writes sequentially in main thread after each sub-level completes
                 self.apply_memory_writes(memory_write_receiver)?;
+                
+                if let (Some(profiler), Some(sub_level_start)) = (self.profiler.as_mut(), sub_level_start) {
+                    profiler.record_sub_level(stage_index, sub_level_index, sub_level_start, &timings);
+                }
+            }
+            
+            if let (Some(profiler), Some(stage_start)) = (self.profiler.as_mut(), stage_start) {
+                profiler.record_since(ProfileKind::Stage, &format!("stage {}", stage_index), stage_start);
             }
         }
         
         // Memory phase: Execute memory components sequentially after parallel processing completes
         // This ensures all memory components are properly updated for the next cycle
-        for component_id in self.memory_components.keys().cloned().collect::<Vec<_>>() {
+        for component_id in self.active_memory_components() {
             self.execute_memory_component(&component_id)?;
         }
-        Ok(())
+        self.end_cycle()
     }
 
     /// Execute a processing component
@@ -333,23 +1012,40 @@ impl CycleEngine {
         
         // Get current cycle before creating memory proxy to avoid borrowing conflict
         let current_cycle = self.current_cycle;
+        let profiling = self.profiler.is_some();
+        let (mut rng, mut external) = self.take_rng(component_id);
         
         // Create memory proxy with component subset for thread safety
         let mut memory_proxy = self.create_component_memory_proxy(component_id)?;
         
+        let mut stats = StatsRecorder::new();
+        
         // Create evaluation context
         let mut context = EvaluationContext {
             inputs: &inputs,
             memory: &mut memory_proxy,
             state: None, // Processing components have no state
             component_id,
+            stats: &mut stats,
+            rng: &mut rng,
+            external: &mut external,
+            tokens: TokenFactory::new(component_id, current_cycle),
         };
         
         // Create output map for this component
         let mut outputs = EventOutputMap::new_flexible(current_cycle);
         
         // Execute the component's evaluation function
-        (processor.evaluate_fn)(&mut context, &mut outputs)?;
+        let evaluate_start = profiling.then(Instant::now);
+        let result = (processor.evaluate_fn)(&mut context, &mut outputs);
+        let evaluate_time = evaluate_start.map(|start| start.elapsed());
+        let memory_access = memory_proxy.take_access_stats();
+        self.return_rng(component_id, rng, external);
+        result?;
+        self.merge_component_stats(stats, memory_access);
+        if let (Some(profiler), Some(start), Some(evaluate_time)) = (self.profiler.as_mut(), evaluate_start, evaluate_time) {
+            profiler.record(ProfileKind::Component, component_id.id(), start, evaluate_time, 0);
+        }
         
         // Store outputs in buffer for next cycle
         for (port, event) in outputs.into_event_map() {
@@ -361,12 +1057,18 @@ impl CycleEngine {
 
     /// Execute a processing component in parallel using channel-based memory synchronization
     /// This method uses &self instead of &mut self for parallel execution
-    /// Memory writes are sent through channels to the main thread for sequential application
+    /// Memory writes are sent through channels to the main thread for sequential application.
+    /// Timings are only taken when `profiling` is set.
     fn execute_processing_component_parallel(
         &self, 
         component_id: &ComponentId,
-        memory_write_sender: mpsc::Sender<MemoryWrite>
-    ) -> Result<HashMap<(ComponentId, String), Event>, String> {
+        mut rng: ComponentRng,
+        mut external: ExternalInputs,
+        memory_write_sender: mpsc::Sender<MemoryWrite>,
+        profiling: bool,
+    ) -> Result<ComponentOutcome, String> {
+        let task_start = profiling.then(Instant::now);
+        
         // First, collect inputs from connected outputs
         let inputs = self.collect_inputs(component_id)?;
         
@@ -409,6 +1111,9 @@ impl CycleEngine {
         // Create output map for this component
         let mut outputs = EventOutputMap::new_flexible(self.current_cycle);
         
+        let mut stats = StatsRecorder::new();
+        let evaluate_start = profiling.then(Instant::now);
+        
         // Execute the component's evaluation function
         {
             let mut context = EvaluationContext {
@@ -416,10 +1121,15 @@ impl CycleEngine {
                 memory: &mut memory_proxy,
                 state: None, // Processing components have no state
                 component_id,
+                stats: &mut stats,
+                rng: &mut rng,
+                external: &mut external,
+                tokens: TokenFactory::new(component_id, self.current_cycle),
             };
             
             (processor.evaluate_fn)(&mut context, &mut outputs)?;
         }
+        let evaluate_time = evaluate_start.map(|start| start.elapsed());
         
         // Return outputs with component ID in key for parallel execution
         let mut component_outputs = HashMap::new();
@@ -427,7 +1137,19 @@ impl CycleEngine {
             component_outputs.insert((component_id.clone(), port), event);
         }
         
-        Ok(component_outputs)
+        Ok(ComponentOutcome {
+            outputs: component_outputs,
+            stats,
+            memory_access: memory_proxy.take_access_stats(),
+            timing: task_start.zip(evaluate_start).zip(evaluate_time).map(|((task_start, start), evaluate)| TaskTiming {
+                start,
+                evaluate,
+                task: task_start.elapsed(),
+                thread: Profiler::current_thread(),
+            }),
+            rng,
+            external,
+        })
     }
 
     /// Execute a memory component
@@ -436,11 +1158,19 @@ impl CycleEngine {
             .ok_or_else(|| format!("Memory component '{}' not found", component_id))?;
         
         // Call cycle() on stored data objects to process pending operations
+        let profiling = self.profiler.is_some();
+        let cycle_start = profiling.then(Instant::now);
         memory_module.cycle()?;
+        let cycle_end = profiling.then(Instant::now);
         
         // Update memory state: current → snapshot for next cycle
         memory_module.create_snapshot();
         
+        if let (Some(profiler), Some(cycle_start), Some(cycle_end)) = (self.profiler.as_mut(), cycle_start, cycle_end) {
+            profiler.record(ProfileKind::MemoryCycle, component_id.id(), cycle_start, cycle_end - cycle_start, 0);
+            profiler.record(ProfileKind::MemorySnapshot, component_id.id(), cycle_end, cycle_end.elapsed(), 0);
+        }
+        
         Ok(())
     }
     
@@ -498,6 +1228,16 @@ impl CycleEngine {
     }
 
 
+    /// Get the model diagnostics produced when the engine was built
+    pub fn diagnostics(&self) -> &DiagnosticsReport {
+        &self.diagnostics
+    }
+
+    /// Attach a model diagnostics report to this engine
+    pub fn set_diagnostics(&mut self, report: DiagnosticsReport) {
+        self.diagnostics = report;
+    }
+
     /// Get the current cycle number
     pub fn current_cycle(&self) -> u64 {
         self.current_cycle
@@ -518,20 +1258,7 @@ impl CycleEngine {
 
     /// Build execution order for deterministic simulation
     pub fn build_execution_order(&mut self) -> Result<(), String> {
-        // Get all processing component IDs
-        let processing_components: Vec<ComponentId> = self.processing_components.keys().cloned().collect();
-        
-        // Build topologically sorted execution order (staged)
-        self.execution_order = ExecutionOrderBuilder::build_execution_order_stages(
-            &processing_components,
-            &self.connections,
-        )?;
-        
-        // Build sub-level execution order for enhanced parallel processing
-        self.sub_level_execution_order = ExecutionOrderBuilder::build_execution_order_with_sub_levels(
-            &processing_components,
-            &self.connections,
-        )?;
+        self.rebuild_stages()?;
         
         // Build input connection lookup for O(1) access (hot path optimization)
         // This pre-computation eliminates O(n×m) linear scanning in collect_inputs()
@@ -556,6 +1283,27 @@ impl CycleEngine {
         Ok(())
     }
 
+    /// Recompute the staged and sub-level execution orders from the connection graph
+    fn rebuild_stages(&mut self) -> Result<(), String> {
+        // Get all processing component IDs
+        let processing_components: Vec<ComponentId> = self.processing_components.keys().cloned().collect();
+        
+        // Build topologically sorted execution order (staged)
+        let execution_order = ExecutionOrderBuilder::build_execution_order_stages(
+            &processing_components,
+            &self.connections,
+        )?;
+        
+        // Build sub-level execution order for enhanced parallel processing
+        self.sub_level_execution_order = ExecutionOrderBuilder::build_execution_order_with_sub_levels(
+            &processing_components,
+            &self.connections,
+        )?;
+        self.execution_order = execution_order;
+        
+        Ok(())
+    }
+
     /// Pre-compute which memory components each processing component needs
     /// This eliminates HashMap contention during parallel execution
     fn pre_compute_memory_subsets(&mut self) {
@@ -577,6 +1325,479 @@ impl CycleEngine {
         }
     }
 
+    /// Set the counter used to name components added after the engine was built
+    pub(crate) fn set_next_component_index(&mut self, index: u64) {
+        self.next_component_index = index;
+    }
+
+    /// Add a processing component between cycles
+    ///
+    /// The new component has no connections and joins the first execution stage.
+    pub fn add_component<T: Component>(&mut self, _component: T) -> ComponentId {
+        let id = self.next_component_id::<T>();
+        self.rngs.insert(id.clone(), ComponentRng::for_component(self.config.seed, &id));
+        self.processing_components.insert(id.clone(), ProcessingComponent {
+            id: id.clone(),
+            module: T::into_module(),
+        });
+        self.insert_into_first_stage(&id);
+        self.idle.insert(id.clone());
+        self.restart_history();
+        id
+    }
+
+    /// Add a memory component between cycles
+    pub fn add_memory_component<T: MemoryComponent + MemoryData>(&mut self, _component: T) -> ComponentId {
+        let id = self.next_component_id::<T>();
+        let module: Box<dyn MemoryModuleTrait> = Box::new(T::into_memory_module());
+        if let Some(initial) = self.initial_state.as_mut() {
+            initial.memory.insert(id.clone(), module.clone_module());
+            initial.memory_stats.insert(id.clone(), MemoryStats::new());
+        }
+        self.memory_stats.insert(id.clone(), MemoryStats::new());
+        self.memory_components.insert(id.clone(), module);
+        self.restart_history();
+        id
+    }
+
+    /// Remove a component between cycles, together with its connections and stimuli
+    ///
+    /// Removing a memory component also disconnects every memory port bound to it.
+    /// Probes on the component are kept but stop recording samples.
+    pub fn remove_component(&mut self, id: &ComponentId) -> Result<(), String> {
+        if !self.has_component(id) {
+            return Err(format!("Component '{}' not found", id));
+        }
+
+        // Port connections in both directions
+        let downstream: Vec<ComponentId> = self.connections.iter()
+            .filter(|((source_id, _), _)| source_id == id)
+            .flat_map(|(_, targets)| targets.iter().map(|(target_id, _)| target_id.clone()))
+            .collect();
+        self.connections.retain(|(source_id, _), targets| {
+            targets.retain(|(target_id, _)| target_id != id);
+            source_id != id && !targets.is_empty()
+        });
+        for target_id in downstream {
+            if let Some(inputs) = self.input_connections.get_mut(&target_id) {
+                inputs.retain(|conn| &conn.source_id != id);
+            }
+        }
+        self.input_connections.remove(id);
+
+        // Memory connections owned by the component or bound to it
+        let mut memory_users = Vec::new();
+        self.memory_connections.retain(|(component_id, _), memory_id| {
+            if memory_id == id && component_id != id {
+                memory_users.push(component_id.clone());
+            }
+            component_id != id && memory_id != id
+        });
+        self.component_memory_map.remove(id);
+        for component_id in memory_users {
+            self.refresh_memory_subset(&component_id);
+        }
+
+        self.stimuli.retain(|(target_id, _), _| target_id != id);
+        self.stimulus_buffer.remove(id);
+        self.controls.retain(|(source_id, _), targets| {
+            targets.retain(|target_id| target_id != id);
+            source_id != id && !targets.is_empty()
+        });
+        self.suspended.remove(id);
+        self.idle.remove(id);
+        self.frozen_memory.remove(id);
+        self.clock_domains.remove(id);
+        self.held_inputs.remove(id);
+        if let Some(initial) = self.initial_state.as_mut() {
+            initial.memory.remove(id);
+            initial.memory_stats.remove(id);
+            initial.rngs.remove(id);
+            initial.suspended.remove(id);
+            initial.idle.remove(id);
+            initial.frozen_memory.remove(id);
+            initial.held_inputs.remove(id);
+        }
+        self.output_buffer.retain(|(source_id, _), _| source_id != id);
+        self.rngs.remove(id);
+        self.rng_cycle_start.remove(id);
+        self.memory_stats.remove(id);
+        self.memory_components.remove(id);
+        if self.processing_components.remove(id).is_some() {
+            self.remove_from_stages(id);
+        }
+
+        self.restart_history();
+        Ok(())
+    }
+
+    /// Connect two component ports between cycles
+    ///
+    /// Applies the same checks as `Simulation::connect`. The execution order is
+    /// kept when the source already runs in an earlier stage than the target;
+    /// otherwise it is rebuilt, and the connection is rejected if it would
+    /// create a dependency cycle.
+    pub fn add_connection(&mut self, source: OutputPort, target: InputPort) -> Result<(), String> {
+        let source_id = source.component_id;
+        let source_port = source.port_name;
+        let target_id = target.component_id;
+        let target_port = target.port_name;
+
+        let source_component = self.processing_components.get(&source_id)
+            .ok_or_else(|| self.missing_processing_component("Source", &source_id))?;
+        if !source_component.module.has_output_port(&source_port) {
+            return Err(format!("Component '{}' does not have output port '{}'", source_id, source_port));
+        }
+        let target_component = self.processing_components.get(&target_id)
+            .ok_or_else(|| self.missing_processing_component("Target", &target_id))?;
+        if !target_component.module.has_input_port(&target_port) {
+            return Err(format!("Component '{}' does not have input port '{}'", target_id, target_port));
+        }
+        let source_key = (source_id.clone(), source_port.clone());
+        if self.connections.contains_key(&source_key) {
+            return Err(format!("Output port '{}' on component '{}' is already connected", source_port, source_id));
+        }
+        let target_key = (target_id.clone(), target_port.clone());
+        let target_connected = self.connections.values()
+            .any(|targets| targets.contains(&target_key));
+        if target_connected {
+            return Err(format!("Input port '{}' on component '{}' is already connected", target_port, target_id));
+        }
+        if self.stimuli.contains_key(&target_key) {
+            return Err(format!("Input port '{}' on component '{}' is already driven by a stimulus", target_port, target_id));
+        }
+
+        self.connections.insert(source_key.clone(), vec![target_key]);
+        self.input_connections.entry(target_id.clone()).or_default().push(InputConnection {
+            source_id: source_id.clone(),
+            source_port,
+            target_port,
+        });
+
+        let in_order = match (self.stage_of(&source_id), self.stage_of(&target_id)) {
+            (Some(source_stage), Some(target_stage)) => source_stage < target_stage,
+            // Execution order not built yet; build_execution_order will compute it
+            _ => self.execution_order.is_empty(),
+        };
+        if !in_order {
+            if let Err(error) = self.rebuild_stages() {
+                self.connections.remove(&source_key);
+                if let Some(inputs) = self.input_connections.get_mut(&target_id) {
+                    inputs.pop();
+                }
+                return Err(format!("Cannot connect '{}' to '{}': {}", source_id, target_id, error));
+            }
+        }
+
+        self.restart_history();
+        Ok(())
+    }
+
+    /// Disconnect an output port between cycles
+    ///
+    /// The execution order stays valid, since removing an edge never breaks it.
+    pub fn remove_connection(&mut self, source: &OutputPort) -> Result<(), String> {
+        let source_key = (source.component_id.clone(), source.port_name.clone());
+        let targets = self.connections.remove(&source_key)
+            .ok_or_else(|| format!("Output port '{}' on component '{}' is not connected", source.port_name, source.component_id))?;
+        for (target_id, target_port) in targets {
+            if let Some(inputs) = self.input_connections.get_mut(&target_id) {
+                inputs.retain(|conn| {
+                    conn.source_id != source_key.0 || conn.source_port != source_key.1 || conn.target_port != target_port
+                });
+            }
+        }
+        self.restart_history();
+        Ok(())
+    }
+
+    /// Bind a component memory port to a memory component between cycles
+    pub fn add_memory_connection(&mut self, component_port: MemoryPort, memory_id: ComponentId) -> Result<(), String> {
+        let comp_id = component_port.component_id;
+        let port_name = component_port.port_name;
+
+        if !self.memory_components.contains_key(&memory_id) {
+            return Err(if self.has_component(&memory_id) {
+                format!("Component '{}' is not a memory component", memory_id)
+            } else {
+                format!("Memory component '{}' not found", memory_id)
+            });
+        }
+        let component = self.processing_components.get(&comp_id)
+            .ok_or_else(|| self.missing_processing_component("Source", &comp_id))?;
+        if !component.module.has_memory_port(&port_name) {
+            return Err(format!("Component '{}' does not have memory port '{}'", comp_id, port_name));
+        }
+        let connection_key = (comp_id.clone(), port_name);
+        if self.memory_connections.contains_key(&connection_key) {
+            return Err(format!("Memory port '{}' on component '{}' is already connected", connection_key.1, comp_id));
+        }
+
+        self.memory_connections.insert(connection_key, memory_id);
+        self.refresh_memory_subset(&comp_id);
+        self.restart_history();
+        Ok(())
+    }
+
+    /// Unbind a component memory port between cycles
+    pub fn remove_memory_connection(&mut self, component_port: &MemoryPort) -> Result<(), String> {
+        let connection_key = (component_port.component_id.clone(), component_port.port_name.clone());
+        if self.memory_connections.remove(&connection_key).is_none() {
+            return Err(format!("Memory port '{}' on component '{}' is not connected", connection_key.1, connection_key.0));
+        }
+        self.refresh_memory_subset(&connection_key.0);
+        self.restart_history();
+        Ok(())
+    }
+
+    /// Generate an unused `{TypeName}{n}` ID, continuing the builder's numbering
+    fn next_component_id<T>(&mut self) -> ComponentId {
+        let type_name = std::any::type_name::<T>();
+        let clean_type_name = type_name.split("::").last().unwrap_or(type_name);
+        loop {
+            let id = format!("{}{}", clean_type_name, self.next_component_index);
+            self.next_component_index += 1;
+            if !self.component_ids().iter().any(|existing| existing.id() == id) {
+                return ComponentId::new(id, type_name.to_string());
+            }
+        }
+    }
+
+    /// Error for a connection endpoint that is missing or not a processing component
+    fn missing_processing_component(&self, role: &str, id: &ComponentId) -> String {
+        if self.has_component(id) {
+            format!("{} component '{}' is not a processing component", role, id)
+        } else {
+            format!("{} component '{}' not found", role, id)
+        }
+    }
+
+    /// Index of the execution stage containing a processing component
+    fn stage_of(&self, id: &ComponentId) -> Option<usize> {
+        self.execution_order.iter().position(|stage| stage.contains(id))
+    }
+
+    /// Place an unconnected component in the first stage, which never has internal dependencies
+    fn insert_into_first_stage(&mut self, id: &ComponentId) {
+        if self.execution_order.is_empty() {
+            self.execution_order.push(Vec::new());
+            self.sub_level_execution_order.push(Stage { sub_levels: vec![SubLevel { components: Vec::new() }] });
+        }
+        let stage = &mut self.execution_order[0];
+        let position = stage.binary_search(id).unwrap_or_else(|position| position);
+        stage.insert(position, id.clone());
+        let sub_level = &mut self.sub_level_execution_order[0].sub_levels[0].components;
+        let position = sub_level.binary_search(id).unwrap_or_else(|position| position);
+        sub_level.insert(position, id.clone());
+    }
+
+    /// Drop a component from the execution order, removing stages left empty
+    fn remove_from_stages(&mut self, id: &ComponentId) {
+        let Some(stage_index) = self.stage_of(id) else {
+            return;
+        };
+        self.execution_order[stage_index].retain(|component_id| component_id != id);
+        if let Some(stage) = self.sub_level_execution_order.get_mut(stage_index) {
+            for sub_level in &mut stage.sub_levels {
+                sub_level.components.retain(|component_id| component_id != id);
+            }
+            stage.sub_levels.retain(|sub_level| !sub_level.components.is_empty());
+        }
+        if self.execution_order[stage_index].is_empty() {
+            self.execution_order.remove(stage_index);
+            if stage_index < self.sub_level_execution_order.len() {
+                self.sub_level_execution_order.remove(stage_index);
+            }
+        }
+    }
+
+    /// Recompute the memory components one processing component can access
+    fn refresh_memory_subset(&mut self, component_id: &ComponentId) {
+        let memory_deps: Vec<ComponentId> = self.memory_connections.iter()
+            .filter(|((connected_comp, _port), _)| connected_comp == component_id)
+            .map(|(_, memory_id)| memory_id.clone())
+            .collect();
+        if memory_deps.is_empty() {
+            self.component_memory_map.remove(component_id);
+        } else {
+            self.component_memory_map.insert(component_id.clone(), memory_deps);
+        }
+    }
+
+    /// Restart time-travel history at the current cycle, discarding snapshots that can no longer be restored
+    fn restart_history(&mut self) {
+        if let Some(history) = &self.history {
+            let (interval, capacity) = (history.interval(), history.capacity());
+            self.enable_time_travel(interval, capacity);
+        }
+    }
+
+    /// Suspend a processing or memory component from the next cycle on
+    ///
+    /// A suspended processing component is not evaluated and emits no events.
+    /// A suspended memory component is not cycled and keeps its contents frozen;
+    /// writes made to it while suspended are discarded when it resumes.
+    pub fn suspend(&mut self, id: &ComponentId) -> Result<(), String> {
+        if !self.has_component(id) {
+            return Err(format!("Component '{}' not found", id));
+        }
+        if self.suspended.insert(id.clone()) {
+            if let Some(module) = self.memory_components.get(id) {
+                self.frozen_memory.insert(id.clone(), module.clone_module());
+            }
+        }
+        Ok(())
+    }
+
+    /// Resume a suspended component from the next cycle on
+    pub fn resume(&mut self, id: &ComponentId) -> Result<(), String> {
+        if !self.has_component(id) {
+            return Err(format!("Component '{}' not found", id));
+        }
+        if self.suspended.remove(id) {
+            if let Some(module) = self.frozen_memory.remove(id) {
+                self.memory_components.insert(id.clone(), module);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if a processing component was evaluated in the current cycle
+    ///
+    /// Unlike `is_suspended`, this reflects the cycle that just ran even if
+    /// the component was suspended or resumed at its end.
+    pub fn was_evaluated(&self, id: &ComponentId) -> bool {
+        self.current_cycle > 0 && self.processing_components.contains_key(id) && !self.idle.contains(id)
+    }
+
+    /// Check if a component is suspended
+    pub fn is_suspended(&self, id: &ComponentId) -> bool {
+        self.suspended.contains(id)
+    }
+
+    /// Get all suspended components, sorted
+    pub fn suspended_components(&self) -> Vec<&ComponentId> {
+        let mut ids: Vec<&ComponentId> = self.suspended.iter().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Gate a component from a bool output port of another component
+    ///
+    /// In each cycle the port emits, `false` suspends and `true` resumes the
+    /// target from the following cycle on; cycles without an event leave it unchanged.
+    /// One control port may gate several components.
+    pub fn connect_control(&mut self, source: OutputPort, target: ComponentId) -> Result<(), String> {
+        let source_component = self.processing_components.get(&source.component_id)
+            .ok_or_else(|| self.missing_processing_component("Source", &source.component_id))?;
+        if !source_component.module.has_output_port(&source.port_name) {
+            return Err(format!("Component '{}' does not have output port '{}'", source.component_id, source.port_name));
+        }
+        if !self.has_component(&target) {
+            return Err(format!("Target component '{}' not found", target));
+        }
+        let targets = self.controls.entry((source.component_id, source.port_name)).or_default();
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+        Ok(())
+    }
+
+    /// Apply the suspend and resume requests emitted on control ports this cycle
+    fn apply_controls(&mut self) -> Result<(), String> {
+        if self.controls.is_empty() {
+            return Ok(());
+        }
+        let mut sources: Vec<&(ComponentId, String)> = self.controls.keys().collect();
+        sources.sort();
+        let mut changes = Vec::new();
+        for source in sources {
+            let Some(event) = self.output_buffer.get(source) else {
+                continue;
+            };
+            let enabled = *event.payload.as_any().downcast_ref::<bool>()
+                .ok_or_else(|| format!("Control port '{}' on component '{}' must emit bool values", source.1, source.0))?;
+            for target in &self.controls[source] {
+                changes.push((target.clone(), enabled));
+            }
+        }
+        for (target, enabled) in changes {
+            if enabled {
+                self.resume(&target)?;
+            } else {
+                self.suspend(&target)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if a component runs this cycle: not suspended and its clock domain ticks
+    fn is_active(&self, id: &ComponentId) -> bool {
+        !self.suspended.contains(id)
+            && self.clock_domains.get(id).is_none_or(|domain| domain.ticks_at(self.current_cycle))
+    }
+
+    /// Filter out components that do not run this cycle, keeping the given order
+    fn active_components(&self, components: &[ComponentId]) -> Vec<ComponentId> {
+        components.iter().filter(|id| self.is_active(id)).cloned().collect()
+    }
+
+    /// Memory components that are cycled and snapshotted this cycle
+    fn active_memory_components(&self) -> Vec<ComponentId> {
+        self.memory_components.keys().filter(|id| self.is_active(id)).cloned().collect()
+    }
+
+    /// Assign a component to a clock domain
+    ///
+    /// The component only runs in cycles where the domain ticks; for a memory
+    /// component, writes become visible to readers at its next tick. Events
+    /// sent to a component between its ticks are held, latest per port, and
+    /// delivered at its next tick.
+    pub fn set_clock_domain(&mut self, id: &ComponentId, domain: ClockDomain) -> Result<(), String> {
+        if !self.has_component(id) {
+            return Err(format!("Component '{}' not found", id));
+        }
+        domain.validate()?;
+        if domain.is_base_rate() {
+            self.clock_domains.remove(id);
+            self.held_inputs.remove(id);
+        } else {
+            self.clock_domains.insert(id.clone(), domain);
+        }
+        self.restart_history();
+        Ok(())
+    }
+
+    /// Get the clock domain of a component
+    pub fn clock_domain(&self, id: &ComponentId) -> ClockDomain {
+        self.clock_domains.get(id).copied().unwrap_or_default()
+    }
+
+    /// Hold events for components whose domain did not tick, and release those delivered this cycle
+    fn hold_cross_domain_events(&mut self) {
+        let cycle = self.current_cycle;
+        for (target_id, domain) in &self.clock_domains {
+            if domain.ticks_at(cycle) {
+                self.held_inputs.remove(target_id);
+                continue;
+            }
+            let mut events = Vec::new();
+            if let Some(connections) = self.input_connections.get(target_id) {
+                for conn in connections {
+                    if let Some(event) = self.output_buffer.get(&(conn.source_id.clone(), conn.source_port.clone())) {
+                        events.push((conn.target_port.clone(), event.clone()));
+                    }
+                }
+            }
+            if let Some(stimulus_events) = self.stimulus_buffer.get(target_id) {
+                events.extend(stimulus_events.iter().cloned());
+            }
+            if !events.is_empty() {
+                self.held_inputs.entry(target_id.clone()).or_default().extend(events);
+            }
+        }
+    }
+
     /// Create a memory proxy for a specific component with only its required memory components
     /// This eliminates HashMap contention during parallel execution by giving each component
     /// only the memory components it needs
@@ -735,10 +1956,106 @@ impl CycleEngine {
         }
     }
     
+    /// Get the last event emitted on an output port in the current cycle
+    pub fn last_output(&self, component_id: &ComponentId, port: &str) -> Option<&Event> {
+        self.output_buffer.get(&(component_id.clone(), port.to_string()))
+    }
+    
+    /// Get all events emitted in the current cycle, sorted by component and port
+    pub fn output_events(&self) -> Vec<(&ComponentId, &str, &Event)> {
+        let mut events: Vec<(&ComponentId, &str, &Event)> = self.output_buffer
+            .iter()
+            .map(|((comp_id, port), event)| (comp_id, port.as_str(), event))
+            .collect();
+        events.sort_by(|a, b| a.0.cmp(b.0).then_with(|| a.1.cmp(b.1)));
+        events
+    }
+    
+    /// Get the input events a component saw in the current cycle, sorted by port
+    pub fn input_events(&self, component_id: &ComponentId) -> Vec<(&str, &Event)> {
+        let mut events: Vec<(&str, &Event)> = Vec::new();
+        if let Some(connections) = self.input_connections.get(component_id) {
+            for conn in connections {
+                if let Some(event) = self.output_buffer.get(&(conn.source_id.clone(), conn.source_port.clone())) {
+                    events.push((conn.target_port.as_str(), event));
+                }
+            }
+        }
+        if let Some(stimulus_events) = self.stimulus_buffer.get(component_id) {
+            events.extend(stimulus_events.iter().map(|(port, event)| (port.as_str(), event)));
+        }
+        events.sort_by(|a, b| a.0.cmp(b.0));
+        events
+    }
+    
+    /// List all addresses present in a memory module with their data types
+    pub fn memory_addresses(&self, memory_component_id: &ComponentId) -> Result<Vec<MemoryAddressInfo>, String> {
+        let memory_component = self.memory_components.get(memory_component_id)
+            .ok_or_else(|| format!("Memory component '{}' not found", memory_component_id))?;
+        
+        let type_name = memory_component.data_type_name();
+        Ok(memory_component.addresses()
+            .into_iter()
+            .map(|address| MemoryAddressInfo { address, type_name })
+            .collect())
+    }
+    
+    /// Read type-erased data from a memory component snapshot
+    pub fn read_memory_any(&self, memory_component_id: &ComponentId, address: &str) -> Option<Box<dyn std::any::Any + Send>> {
+        self.memory_components.get(memory_component_id)
+            .and_then(|memory_component| memory_component.read_any(address))
+    }
+    
+    /// Get the port connections: (source_id, source_port) -> Vec<(target_id, target_port)>
+    pub fn connections(&self) -> &HashMap<(ComponentId, String), Vec<(ComponentId, String)>> {
+        &self.connections
+    }
+    
+    /// Get the memory connections: (component_id, port) -> memory_id
+    pub fn memory_connections(&self) -> &HashMap<(ComponentId, String), ComponentId> {
+        &self.memory_connections
+    }
+    
+    /// Get the computed execution stages (empty until `build_execution_order` is called)
+    pub fn execution_stages(&self) -> &[Vec<ComponentId>] {
+        &self.execution_order
+    }
+    
+    /// Get the computed stages with their parallel sub-levels
+    pub fn sub_level_stages(&self) -> &[Stage] {
+        &self.sub_level_execution_order
+    }
+    
+    /// Get the module definition (ports and evaluate function) of a processing component
+    pub fn processor_module(&self, component_id: &ComponentId) -> Option<&crate::core::components::module::ProcessorModule> {
+        self.processing_components.get(component_id).map(|component| &component.module)
+    }
+    
+    /// Get all processing component IDs, sorted
+    pub fn processing_component_ids(&self) -> Vec<&ComponentId> {
+        let mut ids: Vec<&ComponentId> = self.processing_components.keys().collect();
+        ids.sort();
+        ids
+    }
+    
+    /// Get all memory component IDs, sorted
+    pub fn memory_component_ids(&self) -> Vec<&ComponentId> {
+        let mut ids: Vec<&ComponentId> = self.memory_components.keys().collect();
+        ids.sort();
+        ids
+    }
+    
     /// Collect inputs for a component from connected outputs (optimized for hot path)
     fn collect_inputs(&self, component_id: &ComponentId) -> Result<EventInputMap, String> {
         let mut inputs = EventInputMap::new();
         
+        // Events held since before this component's clock domain ticked; newer events override them
+        if let Some(held) = self.held_inputs.get(component_id) {
+            for (port, event) in held {
+                inputs.insert_event(port.clone(), event.clone());
+            }
+        }
+        
         // O(1) lookup using pre-computed input connections (hot path optimization)
         if let Some(connections) = self.input_connections.get(component_id) {
             for conn in connections {
@@ -749,6 +2066,13 @@ impl CycleEngine {
             }
         }
         
+        // Events injected by external stimulus sources
+        if let Some(stimulus_events) = self.stimulus_buffer.get(component_id) {
+            for (port, event) in stimulus_events {
+                inputs.insert_event(port.clone(), event.clone());
+            }
+        }
+        
         Ok(inputs)
     }
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+use crate::core::components::module::{MemoryModuleTrait, MemoryStats};
+use crate::core::random::ComponentRng;
+use crate::core::stats::StatsRegistry;
+use crate::core::types::ComponentId;
+use crate::core::verification::monitor::MonitorSnapshot;
+use crate::core::values::events::Event;
+use crate::core::values::typed_value::TypedValue;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+/// Engine state at the end of one cycle
+pub(crate) struct EngineSnapshot {
+    pub cycle: u64,
+    pub memory: HashMap<ComponentId, Box<dyn MemoryModuleTrait>>,
+    pub outputs: HashMap<(ComponentId, String), Event>,
+    pub rngs: HashMap<ComponentId, ComponentRng>,
+    pub stats: StatsRegistry,
+    pub memory_stats: HashMap<ComponentId, MemoryStats>,
+    pub suspended: HashSet<ComponentId>,
+    pub idle: HashSet<ComponentId>,
+    pub frozen_memory: HashMap<ComponentId, Box<dyn MemoryModuleTrait>>,
+    pub held_inputs: HashMap<ComponentId, HashMap<String, Event>>,
+    pub monitors: MonitorSnapshot,
+}
+
+impl Clone for EngineSnapshot {
+    fn clone(&self) -> Self {
+        Self {
+            cycle: self.cycle,
+            memory: self.memory.iter().map(|(id, module)| (id.clone(), module.clone_module())).collect(),
+            outputs: self.outputs.clone(),
+            rngs: self.rngs.clone(),
+            stats: self.stats.clone(),
+            memory_stats: self.memory_stats.clone(),
+            suspended: self.suspended.clone(),
+            idle: self.idle.clone(),
+            frozen_memory: self.frozen_memory.iter().map(|(id, module)| (id.clone(), module.clone_module())).collect(),
+            held_inputs: self.held_inputs.clone(),
+            monitors: self.monitors.clone(),
+        }
+    }
+}
+
+/// Inputs taken from outside the model in one cycle, kept so rewound cycles re-execute identically
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CycleInputs {
+    pub stimuli: Vec<(ComponentId, String, TypedValue)>,
+    pub external: HashMap<ComponentId, Vec<(String, Value)>>,
+}
+
+/// Bounded history of engine states for stepping backwards
+///
+/// A full snapshot is kept every `interval` cycles, at most `capacity` of
+/// them; the oldest is dropped first. Jumping to a cycle restores the nearest
+/// earlier snapshot and re-executes forward, feeding recorded stimuli and
+/// external inputs back in, so the re-executed cycles match the original run.
+pub struct History {
+    interval: u64,
+    capacity: usize,
+    snapshots: VecDeque<EngineSnapshot>,
+    inputs: BTreeMap<u64, CycleInputs>,
+    latest_cycle: u64,
+}
+
+impl History {
+    pub(crate) fn new(interval: u64, capacity: usize, initial: EngineSnapshot) -> Self {
+        let latest_cycle = initial.cycle;
+        Self {
+            interval: interval.max(1),
+            capacity: capacity.max(1),
+            snapshots: VecDeque::from([initial]),
+            inputs: BTreeMap::new(),
+            latest_cycle,
+        }
+    }
+
+    /// Cycles between snapshots
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Maximum number of snapshots kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Earliest cycle that can still be reached
+    pub fn earliest_cycle(&self) -> u64 {
+        self.snapshots.front().map(|snapshot| snapshot.cycle).unwrap_or(self.latest_cycle)
+    }
+
+    /// Latest cycle executed so far; cycles up to here re-execute from recorded inputs
+    pub fn latest_cycle(&self) -> u64 {
+        self.latest_cycle
+    }
+
+    /// Cycles at which snapshots are held, oldest first
+    pub fn snapshot_cycles(&self) -> Vec<u64> {
+        self.snapshots.iter().map(|snapshot| snapshot.cycle).collect()
+    }
+
+    /// Check if `cycle` is executed for the first time
+    pub(crate) fn is_new_cycle(&self, cycle: u64) -> bool {
+        cycle > self.latest_cycle
+    }
+
+    /// Recorded inputs of an already executed cycle
+    pub(crate) fn inputs(&self, cycle: u64) -> Option<&CycleInputs> {
+        if self.is_new_cycle(cycle) {
+            None
+        } else {
+            self.inputs.get(&cycle)
+        }
+    }
+
+    /// Record the stimuli of a newly executed cycle
+    ///
+    /// Cycles without stimuli get an entry too, so re-executing them does not
+    /// poll the live sources.
+    pub(crate) fn record_stimuli(&mut self, cycle: u64, stimuli: Vec<(ComponentId, String, TypedValue)>) {
+        if self.is_new_cycle(cycle) {
+            self.inputs.entry(cycle).or_default().stimuli = stimuli;
+        }
+    }
+
+    /// Record the external inputs a component captured in a newly executed cycle
+    pub(crate) fn record_external(&mut self, cycle: u64, component_id: &ComponentId, values: Vec<(String, Value)>) {
+        if self.is_new_cycle(cycle) {
+            self.inputs.entry(cycle).or_default().external.insert(component_id.clone(), values);
+        }
+    }
+
+    /// Check if a snapshot is due at the end of `cycle`
+    pub(crate) fn wants_snapshot(&self, cycle: u64) -> bool {
+        cycle.is_multiple_of(self.interval)
+            && self.snapshots.back().is_none_or(|snapshot| snapshot.cycle < cycle)
+    }
+
+    /// Mark `cycle` as executed
+    pub(crate) fn finish_cycle(&mut self, cycle: u64) {
+        self.latest_cycle = self.latest_cycle.max(cycle);
+    }
+
+    /// Add a snapshot, dropping the oldest one and its inputs when full
+    pub(crate) fn push_snapshot(&mut self, snapshot: EngineSnapshot) {
+        self.snapshots.push_back(snapshot);
+        while self.snapshots.len() > self.capacity {
+            self.snapshots.pop_front();
+        }
+        let earliest = self.earliest_cycle();
+        self.inputs.retain(|&cycle, _| cycle > earliest);
+    }
+
+    /// Latest snapshot at or before `cycle`
+    pub(crate) fn nearest_snapshot(&self, cycle: u64) -> Option<&EngineSnapshot> {
+        self.snapshots.iter().rev().find(|snapshot| snapshot.cycle <= cycle)
+    }
+}
+
+impl std::fmt::Debug for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("History")
+            .field("interval", &self.interval)
+            .field("capacity", &self.capacity)
+            .field("snapshot_cycles", &self.snapshot_cycles())
+            .field("latest_cycle", &self.latest_cycle)
+            .finish()
+    }
+}
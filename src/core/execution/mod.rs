@@ -2,10 +2,16 @@ pub mod cycle_engine;
 pub mod execution_order;
 pub mod simulation_engine;
 pub mod config;
+pub mod profiler;
+pub mod history;
+pub mod clock;
 
 
 // Re-export commonly used types
-pub use cycle_engine::CycleEngine;
-pub use execution_order::ExecutionOrderBuilder;
+pub use cycle_engine::{CycleEngine, MemoryAddressInfo};
+pub use execution_order::{ExecutionOrderBuilder, Stage, SubLevel};
 pub use simulation_engine::SimulationEngine;
+pub use history::History;
+pub use clock::ClockDomain;
+pub use profiler::{Profiler, ProfileKind, ProfileReport, ProfileEntry, TimingStats, SubLevelTiming};
 pub use config::*;
\ No newline at end of file
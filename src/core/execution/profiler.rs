@@ -0,0 +1,302 @@
+use crate::core::types::ComponentId;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// What a profiled span measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProfileKind {
+    /// A processing component's `evaluate_fn`
+    Component,
+    /// A memory component's `cycle()`
+    MemoryCycle,
+    /// A memory component's `create_snapshot()`
+    MemorySnapshot,
+    /// A whole execution stage
+    Stage,
+    /// A sub-level within a stage (Rayon mode)
+    SubLevel,
+}
+
+impl ProfileKind {
+    /// Category name used in reports and trace files
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProfileKind::Component => "component",
+            ProfileKind::MemoryCycle => "memory_cycle",
+            ProfileKind::MemorySnapshot => "memory_snapshot",
+            ProfileKind::Stage => "stage",
+            ProfileKind::SubLevel => "sub_level",
+        }
+    }
+}
+
+/// Accumulated timings for one profiled item
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TimingStats {
+    pub calls: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+impl TimingStats {
+    fn add(&mut self, duration: Duration) {
+        self.calls += 1;
+        self.total += duration;
+        self.max = self.max.max(duration);
+    }
+
+    /// Mean duration per call
+    pub fn mean(&self) -> Duration {
+        if self.calls == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.calls as f64)
+        }
+    }
+}
+
+/// Accumulated parallel timings for one sub-level
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SubLevelTiming {
+    pub calls: u64,
+    /// Wall time of the whole sub-level
+    pub wall: Duration,
+    /// Summed component task time over all threads
+    pub work: Duration,
+    /// Summed per-cycle time of the busiest thread
+    pub critical: Duration,
+    /// Number of worker threads the sub-level can use: available threads, at most one per component
+    pub threads: usize,
+}
+
+impl SubLevelTiming {
+    /// Ideal speedup of the sub-level: summed work over critical thread time
+    pub fn speedup(&self) -> f64 {
+        if self.critical.is_zero() {
+            1.0
+        } else {
+            self.work.as_secs_f64() / self.critical.as_secs_f64()
+        }
+    }
+
+    /// Parallel efficiency: summed work over (critical thread time × threads)
+    pub fn efficiency(&self) -> f64 {
+        if self.threads == 0 {
+            1.0
+        } else {
+            self.speedup() / self.threads as f64
+        }
+    }
+}
+
+/// Timing of one component task in a parallel sub-level
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TaskTiming {
+    /// When `evaluate_fn` started
+    pub start: Instant,
+    /// Time spent in `evaluate_fn`
+    pub evaluate: Duration,
+    /// Time spent in the whole task, including input collection and proxy setup
+    pub task: Duration,
+    /// Trace thread id (0 = main thread)
+    pub thread: usize,
+}
+
+/// One complete span in Chrome trace-event format
+#[derive(Debug, Clone)]
+struct TraceEvent {
+    name: String,
+    kind: ProfileKind,
+    start_us: f64,
+    duration_us: f64,
+    thread: usize,
+}
+
+/// Wall-time profiler for `CycleEngine`
+///
+/// Enabled with `CycleEngine::enable_profiling`. Besides the aggregated
+/// timings it keeps up to `max_trace_events` spans for a Chrome trace file.
+#[derive(Debug, Clone)]
+pub struct Profiler {
+    epoch: Instant,
+    timings: HashMap<(ProfileKind, String), TimingStats>,
+    sub_levels: BTreeMap<(usize, usize), SubLevelTiming>,
+    trace: Vec<TraceEvent>,
+    max_trace_events: usize,
+}
+
+impl Profiler {
+    /// Default cap on recorded trace spans
+    pub const DEFAULT_MAX_TRACE_EVENTS: usize = 1_000_000;
+
+    /// Create an empty profiler
+    pub fn new() -> Self {
+        Self::with_max_trace_events(Self::DEFAULT_MAX_TRACE_EVENTS)
+    }
+
+    /// Create a profiler keeping at most `max_trace_events` trace spans
+    pub fn with_max_trace_events(max_trace_events: usize) -> Self {
+        Self {
+            epoch: Instant::now(),
+            timings: HashMap::new(),
+            sub_levels: BTreeMap::new(),
+            trace: Vec::new(),
+            max_trace_events,
+        }
+    }
+
+    /// Trace thread id of the calling thread (0 = main thread, rayon workers from 1)
+    pub(crate) fn current_thread() -> usize {
+        rayon::current_thread_index().map(|i| i + 1).unwrap_or(0)
+    }
+
+    /// Record a span that started at `start` and took `duration`
+    pub(crate) fn record(&mut self, kind: ProfileKind, name: &str, start: Instant, duration: Duration, thread: usize) {
+        self.timings.entry((kind, name.to_string())).or_default().add(duration);
+        if self.trace.len() < self.max_trace_events {
+            self.trace.push(TraceEvent {
+                name: name.to_string(),
+                kind,
+                start_us: start.saturating_duration_since(self.epoch).as_secs_f64() * 1e6,
+                duration_us: duration.as_secs_f64() * 1e6,
+                thread,
+            });
+        }
+    }
+
+    /// Record a span that started at `start` and ends now
+    pub(crate) fn record_since(&mut self, kind: ProfileKind, name: &str, start: Instant) {
+        self.record(kind, name, start, start.elapsed(), Self::current_thread());
+    }
+
+    /// Record the component tasks of one parallel sub-level execution
+    pub(crate) fn record_sub_level(
+        &mut self,
+        stage: usize,
+        sub_level: usize,
+        start: Instant,
+        tasks: &[(ComponentId, TaskTiming)],
+    ) {
+        let wall = start.elapsed();
+        let mut per_thread: HashMap<usize, Duration> = HashMap::new();
+        for (component_id, timing) in tasks {
+            *per_thread.entry(timing.thread).or_default() += timing.task;
+            self.record(ProfileKind::Component, component_id.id(), timing.start, timing.evaluate, timing.thread);
+        }
+
+        let entry = self.sub_levels.entry((stage, sub_level)).or_default();
+        entry.calls += 1;
+        entry.wall += wall;
+        entry.work += per_thread.values().sum::<Duration>();
+        entry.critical += per_thread.values().copied().max().unwrap_or_default();
+        entry.threads = rayon::current_num_threads().min(tasks.len());
+        self.record(ProfileKind::SubLevel, &format!("stage {} / sub-level {}", stage, sub_level), start, wall, 0);
+    }
+
+    /// Get accumulated timings for one item
+    pub fn timing(&self, kind: ProfileKind, name: &str) -> Option<&TimingStats> {
+        self.timings.get(&(kind, name.to_string()))
+    }
+
+    /// Get accumulated parallel timings for one sub-level
+    pub fn sub_level(&self, stage: usize, sub_level: usize) -> Option<&SubLevelTiming> {
+        self.sub_levels.get(&(stage, sub_level))
+    }
+
+    /// Discard all timings and trace spans
+    pub fn clear(&mut self) {
+        self.timings.clear();
+        self.sub_levels.clear();
+        self.trace.clear();
+    }
+
+    /// Build a report with entries sorted by total time, slowest first
+    pub fn report(&self) -> ProfileReport {
+        let mut entries: Vec<ProfileEntry> = self.timings.iter()
+            .map(|((kind, name), stats)| ProfileEntry { kind: *kind, name: name.clone(), stats: *stats })
+            .collect();
+        entries.sort_by(|a, b| b.stats.total.cmp(&a.stats.total)
+            .then_with(|| a.kind.cmp(&b.kind))
+            .then_with(|| a.name.cmp(&b.name)));
+        ProfileReport {
+            entries,
+            sub_levels: self.sub_levels.iter().map(|(&key, &timing)| (key, timing)).collect(),
+        }
+    }
+
+    /// Render recorded spans as Chrome trace-event JSON (`chrome://tracing`, Perfetto)
+    pub fn to_chrome_trace(&self) -> String {
+        let events: Vec<serde_json::Value> = self.trace.iter()
+            .map(|event| serde_json::json!({
+                "name": event.name,
+                "cat": event.kind.as_str(),
+                "ph": "X",
+                "ts": event.start_us,
+                "dur": event.duration_us,
+                "pid": 1,
+                "tid": event.thread,
+            }))
+            .collect();
+        serde_json::json!({ "traceEvents": events }).to_string()
+    }
+
+    /// Write recorded spans as a Chrome trace-event JSON file
+    pub fn write_chrome_trace<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        let mut file = std::fs::File::create(path)
+            .map_err(|e| format!("Failed to create '{}': {}", path.display(), e))?;
+        file.write_all(self.to_chrome_trace().as_bytes())
+            .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+    }
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One row of a profile report
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileEntry {
+    pub kind: ProfileKind,
+    pub name: String,
+    pub stats: TimingStats,
+}
+
+/// Profile report sorted by total time
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfileReport {
+    pub entries: Vec<ProfileEntry>,
+    /// Parallel timings keyed by (stage, sub-level)
+    pub sub_levels: Vec<((usize, usize), SubLevelTiming)>,
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<16} {:<32} {:>10} {:>14} {:>12} {:>12}", "kind", "name", "calls", "total", "mean", "max")?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{:<16} {:<32} {:>10} {:>14.3?} {:>12.3?} {:>12.3?}",
+                entry.kind.as_str(), entry.name, entry.stats.calls,
+                entry.stats.total, entry.stats.mean(), entry.stats.max
+            )?;
+        }
+        if !self.sub_levels.is_empty() {
+            writeln!(f, "Parallel efficiency:")?;
+            for ((stage, sub_level), timing) in &self.sub_levels {
+                writeln!(
+                    f,
+                    "  stage {} / sub-level {}: wall={:.3?} work={:.3?} critical={:.3?} speedup={:.2} efficiency={:.1}% ({} threads)",
+                    stage, sub_level, timing.wall, timing.work, timing.critical,
+                    timing.speedup(), timing.efficiency() * 100.0, timing.threads
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::BTreeMap;
+
+/// Range of values a sweep parameter can take
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterSpace {
+    /// Explicit levels
+    Discrete(Vec<f64>),
+    /// Every integer in `min..=max`
+    Integer { min: i64, max: i64 },
+    /// Real interval; full-factorial designs use `levels` evenly spaced points
+    Continuous { min: f64, max: f64, levels: usize },
+}
+
+impl ParameterSpace {
+    /// Levels used by a full-factorial design
+    pub fn levels(&self) -> Vec<f64> {
+        match self {
+            ParameterSpace::Discrete(values) => values.clone(),
+            ParameterSpace::Integer { min, max } => (*min..=*max).map(|v| v as f64).collect(),
+            ParameterSpace::Continuous { min, max, levels } => match levels {
+                0 => Vec::new(),
+                1 => vec![(min + max) / 2.0],
+                n => (0..*n).map(|i| min + (max - min) * i as f64 / (n - 1) as f64).collect(),
+            },
+        }
+    }
+
+    /// Map a unit-interval position to a value of this space
+    fn at(&self, unit: f64) -> f64 {
+        match self {
+            ParameterSpace::Discrete(values) => {
+                let index = ((unit * values.len() as f64) as usize).min(values.len() - 1);
+                values[index]
+            }
+            ParameterSpace::Integer { min, max } => {
+                let span = (max - min + 1) as f64;
+                (*min + ((unit * span) as i64).min(max - min)) as f64
+            }
+            ParameterSpace::Continuous { min, max, .. } => min + (max - min) * unit,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            ParameterSpace::Discrete(values) => values.is_empty(),
+            ParameterSpace::Integer { min, max } => min > max,
+            ParameterSpace::Continuous { min, max, .. } => min > max,
+        }
+    }
+}
+
+/// A named sweep parameter
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    pub name: String,
+    pub space: ParameterSpace,
+}
+
+impl Parameter {
+    /// Parameter with explicit levels
+    pub fn discrete(name: &str, values: Vec<f64>) -> Self {
+        Self { name: name.to_string(), space: ParameterSpace::Discrete(values) }
+    }
+
+    /// Integer parameter over `min..=max`
+    pub fn integer(name: &str, min: i64, max: i64) -> Self {
+        Self { name: name.to_string(), space: ParameterSpace::Integer { min, max } }
+    }
+
+    /// Real parameter over `[min, max]` with `levels` factorial levels
+    pub fn continuous(name: &str, min: f64, max: f64, levels: usize) -> Self {
+        Self { name: name.to_string(), space: ParameterSpace::Continuous { min, max, levels } }
+    }
+}
+
+/// One combination of parameter values
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesignPoint {
+    /// Position of the point in its design
+    pub index: usize,
+    pub values: BTreeMap<String, f64>,
+}
+
+impl DesignPoint {
+    /// Get a parameter value
+    pub fn get(&self, name: &str) -> Result<f64, String> {
+        self.values.get(name)
+            .copied()
+            .ok_or_else(|| format!("Design point has no parameter '{}'", name))
+    }
+
+    /// Get a parameter value as an integer
+    pub fn get_i64(&self, name: &str) -> Result<i64, String> {
+        self.get(name).map(|v| v.round() as i64)
+    }
+
+    /// Get a parameter value as a non-negative count
+    pub fn get_usize(&self, name: &str) -> Result<usize, String> {
+        let value = self.get_i64(name)?;
+        usize::try_from(value).map_err(|_| format!("Parameter '{}' is negative: {}", name, value))
+    }
+}
+
+/// Generates design points from parameter spaces
+pub struct DesignBuilder;
+
+impl DesignBuilder {
+    /// Every combination of every parameter's levels (last parameter varies fastest)
+    pub fn full_factorial(parameters: &[Parameter]) -> Result<Vec<DesignPoint>, String> {
+        Self::validate(parameters)?;
+        let mut points: Vec<BTreeMap<String, f64>> = vec![BTreeMap::new()];
+        for parameter in parameters {
+            let levels = parameter.space.levels();
+            if levels.is_empty() {
+                return Err(format!("Parameter '{}' has no levels", parameter.name));
+            }
+            points = points.into_iter()
+                .flat_map(|point| levels.iter().map(move |&level| {
+                    let mut point = point.clone();
+                    point.insert(parameter.name.clone(), level);
+                    point
+                }).collect::<Vec<_>>())
+                .collect();
+        }
+        Ok(points.into_iter().enumerate().map(|(index, values)| DesignPoint { index, values }).collect())
+    }
+
+    /// Latin-hypercube design with `samples` points, reproducible from `seed`
+    ///
+    /// Each parameter's range is split into `samples` equal strata and every
+    /// stratum is used exactly once.
+    pub fn latin_hypercube(parameters: &[Parameter], samples: usize, seed: u64) -> Result<Vec<DesignPoint>, String> {
+        Self::validate(parameters)?;
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut points: Vec<BTreeMap<String, f64>> = vec![BTreeMap::new(); samples];
+        for parameter in parameters {
+            let mut strata: Vec<usize> = (0..samples).collect();
+            strata.shuffle(&mut rng);
+            for (point, stratum) in points.iter_mut().zip(strata) {
+                let unit = (stratum as f64 + rng.gen::<f64>()) / samples as f64;
+                point.insert(parameter.name.clone(), parameter.space.at(unit));
+            }
+        }
+        Ok(points.into_iter().enumerate().map(|(index, values)| DesignPoint { index, values }).collect())
+    }
+
+    fn validate(parameters: &[Parameter]) -> Result<(), String> {
+        let mut seen = std::collections::HashSet::new();
+        for parameter in parameters {
+            if !seen.insert(parameter.name.as_str()) {
+                return Err(format!("Duplicate parameter '{}'", parameter.name));
+            }
+            if parameter.space.is_empty() {
+                return Err(format!("Parameter '{}' has an empty range", parameter.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_factorial() {
+        let parameters = vec![
+            Parameter::integer("fryers", 1, 3),
+            Parameter::discrete("capacity", vec![5.0, 10.0]),
+        ];
+        let points = DesignBuilder::full_factorial(&parameters).unwrap();
+        assert_eq!(points.len(), 6);
+        assert_eq!(points[1].get_usize("fryers").unwrap(), 1);
+        assert_eq!(points[1].get("capacity").unwrap(), 10.0);
+        assert_eq!(points[5].get_usize("fryers").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_latin_hypercube_covers_every_stratum() {
+        let parameters = vec![
+            Parameter::continuous("delay", 0.0, 10.0, 0),
+            Parameter::integer("fryers", 0, 4),
+        ];
+        let points = DesignBuilder::latin_hypercube(&parameters, 5, 7).unwrap();
+        let mut strata: Vec<usize> = points.iter().map(|p| (p.get("delay").unwrap() / 2.0) as usize).collect();
+        strata.sort();
+        assert_eq!(strata, vec![0, 1, 2, 3, 4]);
+        let mut fryers: Vec<i64> = points.iter().map(|p| p.get_i64("fryers").unwrap()).collect();
+        fryers.sort();
+        assert_eq!(fryers, vec![0, 1, 2, 3, 4]);
+        assert_eq!(points, DesignBuilder::latin_hypercube(&parameters, 5, 7).unwrap());
+    }
+}
@@ -0,0 +1,8 @@
+pub mod replication;
+pub mod design;
+pub mod sweep;
+
+// Re-export commonly used types
+pub use replication::{ReplicationRunner, ReplicationResults, Metric, ModelFactory};
+pub use design::{Parameter, ParameterSpace, DesignPoint, DesignBuilder};
+pub use sweep::{ParameterSweep, SweepResults, SweepRow};
@@ -0,0 +1,176 @@
+use crate::core::execution::cycle_engine::CycleEngine;
+use crate::core::stats::Summary;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A named output metric extracted from a finished replication
+pub type Metric = Box<dyn Fn(&CycleEngine) -> f64 + Send + Sync>;
+
+/// Model factory: builds a fresh engine for the given master seed
+pub type ModelFactory = Box<dyn Fn(u64) -> Result<CycleEngine, String> + Send + Sync>;
+
+/// Monte Carlo runner that executes independent replications of one model in parallel
+///
+/// Replication `i` uses master seed `base_seed + i`, so a run is fully
+/// reproducible regardless of how replications are scheduled across cores.
+///
+/// ```ignore
+/// let results = ReplicationRunner::new(|seed| build_model(config.clone().with_seed(seed)))
+///     .replications(30)
+///     .cycles(10_000)
+///     .metric("served", |engine| engine.stats().counter("served") as f64)
+///     .run()?;
+/// println!("{}", results);
+/// ```
+pub struct ReplicationRunner {
+    factory: ModelFactory,
+    replications: usize,
+    base_seed: u64,
+    cycles: u64,
+    metrics: Vec<(String, Metric)>,
+}
+
+impl ReplicationRunner {
+    /// Create a runner from a model factory taking the master seed
+    pub fn new<F>(factory: F) -> Self
+    where
+        F: Fn(u64) -> Result<CycleEngine, String> + Send + Sync + 'static,
+    {
+        Self {
+            factory: Box::new(factory),
+            replications: 10,
+            base_seed: 0,
+            cycles: 1000,
+            metrics: Vec::new(),
+        }
+    }
+
+    /// Set the number of replications (default 10)
+    pub fn replications(mut self, replications: usize) -> Self {
+        self.replications = replications;
+        self
+    }
+
+    /// Set the seed of the first replication (default 0)
+    pub fn base_seed(mut self, seed: u64) -> Self {
+        self.base_seed = seed;
+        self
+    }
+
+    /// Set the number of cycles each replication runs (default 1000)
+    pub fn cycles(mut self, cycles: u64) -> Self {
+        self.cycles = cycles;
+        self
+    }
+
+    /// Add an output metric evaluated on each engine after its run
+    pub fn metric<F>(mut self, name: &str, metric: F) -> Self
+    where
+        F: Fn(&CycleEngine) -> f64 + Send + Sync + 'static,
+    {
+        self.metrics.push((name.to_string(), Box::new(metric)));
+        self
+    }
+
+    /// Seed used by replication `index`
+    pub fn seed_for(&self, index: usize) -> u64 {
+        self.base_seed.wrapping_add(index as u64)
+    }
+
+    /// Run a single replication and return its metric values in declaration order
+    pub fn run_one(&self, seed: u64) -> Result<Vec<f64>, String> {
+        let mut engine = (self.factory)(seed)?;
+        engine.build_execution_order()?;
+        for _ in 0..self.cycles {
+            engine.cycle()?;
+        }
+        Ok(self.metrics.iter().map(|(_, metric)| metric(&engine)).collect())
+    }
+
+    /// Run all replications in parallel
+    pub fn run(&self) -> Result<ReplicationResults, String> {
+        if self.metrics.is_empty() {
+            return Err("No metrics registered for replication runner".to_string());
+        }
+        let seeds: Vec<u64> = (0..self.replications).map(|i| self.seed_for(i)).collect();
+        let outcomes: Vec<Result<Vec<f64>, String>> = seeds
+            .par_iter()
+            .map(|&seed| self.run_one(seed))
+            .collect();
+
+        let mut values: BTreeMap<String, Vec<f64>> = self.metrics.iter()
+            .map(|(name, _)| (name.clone(), Vec::with_capacity(self.replications)))
+            .collect();
+        for (index, outcome) in outcomes.into_iter().enumerate() {
+            let row = outcome.map_err(|e| format!("Replication {} (seed {}) failed: {}", index, seeds[index], e))?;
+            for ((name, _), value) in self.metrics.iter().zip(row) {
+                values.get_mut(name).expect("metric registered").push(value);
+            }
+        }
+
+        Ok(ReplicationResults { seeds, values })
+    }
+}
+
+/// Per-replication metric values with summary statistics
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplicationResults {
+    /// Master seed of each replication
+    pub seeds: Vec<u64>,
+    /// Metric values per replication, in seed order
+    pub values: BTreeMap<String, Vec<f64>>,
+}
+
+impl ReplicationResults {
+    /// Get the per-replication values of a metric
+    pub fn metric(&self, name: &str) -> Option<&[f64]> {
+        self.values.get(name).map(|v| v.as_slice())
+    }
+
+    /// Summarize a metric across replications (mean, std dev, 95% CI, ...)
+    pub fn summary(&self, name: &str) -> Option<Summary> {
+        self.values.get(name).and_then(|v| Summary::from_samples(v))
+    }
+
+    /// Summaries of all metrics
+    pub fn summaries(&self) -> BTreeMap<String, Summary> {
+        self.values.iter()
+            .filter_map(|(name, v)| Summary::from_samples(v).map(|s| (name.clone(), s)))
+            .collect()
+    }
+
+    /// Render one row per replication as CSV: `seed,<metric1>,<metric2>,...`
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("seed");
+        for name in self.values.keys() {
+            csv.push(',');
+            csv.push_str(name);
+        }
+        csv.push('\n');
+        for (index, seed) in self.seeds.iter().enumerate() {
+            csv.push_str(&seed.to_string());
+            for values in self.values.values() {
+                csv.push(',');
+                csv.push_str(&values[index].to_string());
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+impl fmt::Display for ReplicationResults {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} replications", self.seeds.len())?;
+        for (name, s) in self.summaries() {
+            let (low, high) = s.confidence_interval();
+            writeln!(
+                f,
+                "  {}: mean={:.4} sd={:.4} 95% CI=[{:.4}, {:.4}] min={:.4} max={:.4}",
+                name, s.mean, s.std_dev, low, high, s.min, s.max
+            )?;
+        }
+        Ok(())
+    }
+}
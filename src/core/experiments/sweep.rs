@@ -0,0 +1,291 @@
+use crate::core::execution::cycle_engine::CycleEngine;
+use crate::core::experiments::design::DesignPoint;
+use crate::core::experiments::replication::ReplicationRunner;
+use crate::core::stats::Summary;
+use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+type PointFactory = Arc<dyn Fn(&DesignPoint, u64) -> Result<CycleEngine, String> + Send + Sync>;
+type SharedMetric = Arc<dyn Fn(&CycleEngine) -> f64 + Send + Sync>;
+
+/// Runs one model per design point, with replications, and tabulates the metrics
+///
+/// Replication `r` of every design point uses master seed `base_seed + r`
+/// (common random numbers), so differences between points are not masked by
+/// seed noise.
+///
+/// ```ignore
+/// let points = DesignBuilder::full_factorial(&[
+///     Parameter::integer("fryers", 1, 4),
+///     Parameter::discrete("capacity", vec![5.0, 10.0, 20.0]),
+/// ])?;
+/// let results = ParameterSweep::new(points, |point, seed| build_model(point, seed))
+///     .replications(10)
+///     .cycles(5_000)
+///     .metric("served", |engine| engine.stats().counter("served") as f64)
+///     .run_csv("sweep.csv")?;
+/// ```
+pub struct ParameterSweep {
+    points: Vec<DesignPoint>,
+    factory: PointFactory,
+    replications: usize,
+    base_seed: u64,
+    cycles: u64,
+    metrics: Vec<(String, SharedMetric)>,
+}
+
+impl ParameterSweep {
+    /// Create a sweep over `points` using a factory taking the point and master seed
+    pub fn new<F>(points: Vec<DesignPoint>, factory: F) -> Self
+    where
+        F: Fn(&DesignPoint, u64) -> Result<CycleEngine, String> + Send + Sync + 'static,
+    {
+        Self {
+            points,
+            factory: Arc::new(factory),
+            replications: 1,
+            base_seed: 0,
+            cycles: 1000,
+            metrics: Vec::new(),
+        }
+    }
+
+    /// Set the number of replications per design point (default 1)
+    pub fn replications(mut self, replications: usize) -> Self {
+        self.replications = replications;
+        self
+    }
+
+    /// Set the seed of the first replication (default 0)
+    pub fn base_seed(mut self, seed: u64) -> Self {
+        self.base_seed = seed;
+        self
+    }
+
+    /// Set the number of cycles each run executes (default 1000)
+    pub fn cycles(mut self, cycles: u64) -> Self {
+        self.cycles = cycles;
+        self
+    }
+
+    /// Add an output metric evaluated on each engine after its run
+    pub fn metric<F>(mut self, name: &str, metric: F) -> Self
+    where
+        F: Fn(&CycleEngine) -> f64 + Send + Sync + 'static,
+    {
+        self.metrics.push((name.to_string(), Arc::new(metric)));
+        self
+    }
+
+    /// Get the design points of this sweep
+    pub fn points(&self) -> &[DesignPoint] {
+        &self.points
+    }
+
+    /// Run every design point and keep the results in memory
+    pub fn run(&self) -> Result<SweepResults, String> {
+        let mut results = self.empty_results()?;
+        for point in &self.points {
+            results.rows.extend(self.run_point(point)?);
+        }
+        Ok(results)
+    }
+
+    /// Run the sweep, appending rows to a CSV file as each point completes
+    ///
+    /// If the file already holds results from an interrupted run of the same
+    /// sweep, points with all replications present are skipped and incomplete
+    /// points are re-run.
+    pub fn run_csv<P: AsRef<Path>>(&self, path: P) -> Result<SweepResults, String> {
+        let path = path.as_ref();
+        let mut results = self.empty_results()?;
+        if path.exists() {
+            let content = std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+            results.rows = self.completed_rows(&content)?;
+        }
+        std::fs::write(path, results.to_csv())
+            .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+
+        let done: HashSet<usize> = results.rows.iter().map(|row| row.point).collect();
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("Failed to open '{}': {}", path.display(), e))?;
+        for point in self.points.iter().filter(|p| !done.contains(&p.index)) {
+            let rows = self.run_point(point)?;
+            let lines: String = rows.iter().map(|row| row.to_csv_line() + "\n").collect();
+            file.write_all(lines.as_bytes())
+                .and_then(|_| file.flush())
+                .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+            results.rows.extend(rows);
+        }
+        results.rows.sort_by_key(|row| (row.point, row.replication));
+        Ok(results)
+    }
+
+    /// Run all replications of one design point
+    fn run_point(&self, point: &DesignPoint) -> Result<Vec<SweepRow>, String> {
+        let factory = self.factory.clone();
+        let model_point = point.clone();
+        let mut runner = ReplicationRunner::new(move |seed| factory(&model_point, seed))
+            .replications(self.replications)
+            .base_seed(self.base_seed)
+            .cycles(self.cycles);
+        for (name, metric) in &self.metrics {
+            let metric = metric.clone();
+            runner = runner.metric(name, move |engine| metric(engine));
+        }
+        let replications = runner.run()
+            .map_err(|e| format!("Design point {}: {}", point.index, e))?;
+
+        let parameters: Vec<f64> = point.values.values().copied().collect();
+        Ok(replications.seeds.iter().enumerate().map(|(replication, &seed)| SweepRow {
+            point: point.index,
+            parameters: parameters.clone(),
+            replication,
+            seed,
+            metrics: self.metrics.iter()
+                .map(|(name, _)| replications.values[name][replication])
+                .collect(),
+        }).collect())
+    }
+
+    /// Results table with headers but no rows
+    fn empty_results(&self) -> Result<SweepResults, String> {
+        if self.metrics.is_empty() {
+            return Err("No metrics registered for parameter sweep".to_string());
+        }
+        let mut names = HashSet::new();
+        if let Some((name, _)) = self.metrics.iter().find(|(name, _)| !names.insert(name)) {
+            return Err(format!("Duplicate metric '{}'", name));
+        }
+        let parameters: Vec<String> = self.points.first()
+            .map(|p| p.values.keys().cloned().collect())
+            .unwrap_or_default();
+        if let Some(point) = self.points.iter().find(|p| !p.values.keys().eq(parameters.iter())) {
+            return Err(format!("Design point {} has different parameters from the first point", point.index));
+        }
+        Ok(SweepResults {
+            parameters,
+            metrics: self.metrics.iter().map(|(name, _)| name.clone()).collect(),
+            rows: Vec::new(),
+        })
+    }
+
+    /// Parse previous results, keeping only design points with every replication present
+    fn completed_rows(&self, content: &str) -> Result<Vec<SweepRow>, String> {
+        let expected = self.empty_results()?;
+        let mut lines = content.lines();
+        match lines.next() {
+            None => return Ok(Vec::new()),
+            Some(header) if header == expected.header() => {}
+            Some(header) => return Err(format!("Existing results header '{}' does not match this sweep", header)),
+        }
+
+        let mut by_point: BTreeMap<usize, Vec<SweepRow>> = BTreeMap::new();
+        for line in lines {
+            // A truncated final line from an interrupted write is ignored
+            if let Some(row) = SweepRow::parse(line, expected.parameters.len(), expected.metrics.len()) {
+                by_point.entry(row.point).or_default().push(row);
+            }
+        }
+
+        let mut rows = Vec::new();
+        for (index, mut point_rows) in by_point {
+            let point = self.points.iter().find(|p| p.index == index)
+                .ok_or_else(|| format!("Existing results refer to unknown design point {}", index))?;
+            let values: Vec<f64> = point.values.values().copied().collect();
+            if point_rows.iter().any(|row| row.parameters != values) {
+                return Err(format!("Existing results for design point {} do not match this sweep", index));
+            }
+            point_rows.sort_by_key(|row| row.replication);
+            point_rows.dedup_by_key(|row| row.replication);
+            if point_rows.len() == self.replications {
+                rows.extend(point_rows);
+            }
+        }
+        Ok(rows)
+    }
+}
+
+/// One replication of one design point
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepRow {
+    pub point: usize,
+    /// Parameter values in `SweepResults::parameters` order
+    pub parameters: Vec<f64>,
+    pub replication: usize,
+    pub seed: u64,
+    /// Metric values in `SweepResults::metrics` order
+    pub metrics: Vec<f64>,
+}
+
+impl SweepRow {
+    fn to_csv_line(&self) -> String {
+        let mut fields = vec![self.point.to_string()];
+        fields.extend(self.parameters.iter().map(|v| v.to_string()));
+        fields.push(self.replication.to_string());
+        fields.push(self.seed.to_string());
+        fields.extend(self.metrics.iter().map(|v| v.to_string()));
+        fields.join(",")
+    }
+
+    fn parse(line: &str, parameters: usize, metrics: usize) -> Option<Self> {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if fields.len() != parameters + metrics + 3 {
+            return None;
+        }
+        let floats = |slice: &[&str]| slice.iter().map(|f| f.parse::<f64>().ok()).collect::<Option<Vec<_>>>();
+        Some(Self {
+            point: fields[0].parse().ok()?,
+            parameters: floats(&fields[1..=parameters])?,
+            replication: fields[parameters + 1].parse().ok()?,
+            seed: fields[parameters + 2].parse().ok()?,
+            metrics: floats(&fields[parameters + 3..])?,
+        })
+    }
+}
+
+/// Results table of a parameter sweep
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepResults {
+    pub parameters: Vec<String>,
+    pub metrics: Vec<String>,
+    pub rows: Vec<SweepRow>,
+}
+
+impl SweepResults {
+    /// CSV header: `point,<parameters...>,replication,seed,<metrics...>`
+    pub fn header(&self) -> String {
+        let mut fields = vec!["point".to_string()];
+        fields.extend(self.parameters.iter().cloned());
+        fields.push("replication".to_string());
+        fields.push("seed".to_string());
+        fields.extend(self.metrics.iter().cloned());
+        fields.join(",")
+    }
+
+    /// Render the full table as CSV
+    pub fn to_csv(&self) -> String {
+        let mut csv = self.header();
+        csv.push('\n');
+        for row in &self.rows {
+            csv.push_str(&row.to_csv_line());
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Summarize a metric across the replications of one design point
+    pub fn point_summary(&self, point: usize, metric: &str) -> Option<Summary> {
+        let column = self.metrics.iter().position(|m| m == metric)?;
+        let values: Vec<f64> = self.rows.iter()
+            .filter(|row| row.point == point)
+            .map(|row| row.metrics[column])
+            .collect();
+        Summary::from_samples(&values)
+    }
+}
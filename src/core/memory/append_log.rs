@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+/// Append-only sequence that is cheap to clone
+///
+/// Memory values are cloned into the snapshot every cycle, so a log that
+/// keeps everything it was given would cost time proportional to its length
+/// each cycle. Items are instead kept in shared, immutable runs; appending
+/// merges the newest runs like a binary counter, so a clone only copies
+/// O(log n) pointers and each item is copied O(log n) times overall.
+#[derive(Debug, Clone)]
+pub struct AppendLog<T> {
+    /// Runs oldest first, strictly shrinking in length
+    runs: Vec<Arc<Vec<T>>>,
+    len: usize,
+}
+
+impl<T: Clone> AppendLog<T> {
+    /// Create an empty log
+    pub fn new() -> Self {
+        Self { runs: Vec::new(), len: 0 }
+    }
+
+    /// Append one item
+    pub fn push(&mut self, item: T) {
+        self.append(vec![item]);
+    }
+
+    /// Append items, keeping their order
+    pub fn append(&mut self, items: Vec<T>) {
+        if items.is_empty() {
+            return;
+        }
+        self.len += items.len();
+        self.runs.push(Arc::new(items));
+        while let [.., older, newer] = self.runs.as_slice() {
+            if older.len() > newer.len() {
+                break;
+            }
+            let newer = self.runs.pop().expect("two runs");
+            let mut merged = Arc::unwrap_or_clone(self.runs.pop().expect("two runs"));
+            merged.extend(Arc::unwrap_or_clone(newer));
+            self.runs.push(Arc::new(merged));
+        }
+    }
+
+    /// Number of items
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the log holds no items
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Items, oldest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.runs.iter().flat_map(|run| run.iter())
+    }
+}
+
+impl<T: Clone> Default for AppendLog<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq for AppendLog<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs_stay_logarithmic_and_shared() {
+        let mut log = AppendLog::new();
+        for item in 0..1000 {
+            log.push(item);
+        }
+        let snapshot = log.clone();
+        log.append(vec![1000, 1001]);
+
+        assert!(log.runs.len() <= 11, "{} runs", log.runs.len());
+        assert!(log.iter().copied().eq(0..1002));
+        assert_eq!((snapshot.len(), log.len()), (1000, 1002));
+        assert!(Arc::ptr_eq(&snapshot.runs[0], &log.runs[0]));
+        assert_ne!(snapshot, log);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::core::components::state::MemoryData;
+use crate::core::components::traits::{Cycle, MemoryComponent};
+use crate::core::components::types::PortType;
+use std::collections::VecDeque;
+
+/// Memory address a `Channel` is stored at
+pub const CHANNEL_ADDRESS: &str = "channel";
+
+/// Transfer counters of a `Channel`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChannelStats {
+    /// Items offered by the producer
+    pub offered: u64,
+    /// Items handed to the consumer
+    pub transfers: u64,
+    /// Cycles ending with an offer the channel could not accept
+    pub stalls: u64,
+    /// Largest number of items buffered after a cycle
+    pub max_buffered: usize,
+}
+
+/// Point-to-point link with ready/valid handshaking and optional credits
+///
+/// The producer offers an item with `send`, which holds it as valid until the
+/// channel accepts it; until then `can_send` is false and further sends are
+/// refused. The consumer asserts `set_ready` in each cycle it can take an item.
+/// At the end of the cycle the channel hands the consumer its oldest item if
+/// it was ready, then accepts the pending offer into a buffer of `credits`
+/// slots. With no credits an offer is only accepted by a ready consumer.
+/// The producer sees the outcome in the next cycle through `accepted` and
+/// `can_send`, the consumer its item through `received`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Channel<T> {
+    credits: usize,
+    buffer: VecDeque<T>,
+    offer: Option<T>,
+    sending: Option<T>,
+    ready: bool,
+    received: Option<T>,
+    accepted: bool,
+    stats: ChannelStats,
+}
+
+impl<T: MemoryData> Channel<T> {
+    /// Unbuffered channel: a transfer needs a valid offer and a ready consumer in the same cycle
+    pub fn ready_valid() -> Self {
+        Self::with_credits(0)
+    }
+
+    /// Channel buffering up to `credits` items the consumer has not taken yet
+    pub fn with_credits(credits: usize) -> Self {
+        Self {
+            credits,
+            buffer: VecDeque::new(),
+            offer: None,
+            sending: None,
+            ready: false,
+            received: None,
+            accepted: false,
+            stats: ChannelStats::default(),
+        }
+    }
+
+    /// Check if an offer would be taken this cycle, i.e. no earlier offer is still pending
+    pub fn can_send(&self) -> bool {
+        self.offer.is_none() && self.sending.is_none()
+    }
+
+    /// Offer an item, returning it back if an earlier offer is still pending
+    pub fn send(&mut self, item: T) -> Result<(), T> {
+        if !self.can_send() {
+            return Err(item);
+        }
+        self.sending = Some(item);
+        Ok(())
+    }
+
+    /// Check if the producer's offer was accepted at the end of the previous cycle
+    pub fn accepted(&self) -> bool {
+        self.accepted
+    }
+
+    /// Signal the consumer can take an item this cycle
+    pub fn set_ready(&mut self) {
+        self.ready = true;
+    }
+
+    /// Item handed to the consumer at the end of the previous cycle
+    pub fn received(&self) -> Option<&T> {
+        self.received.as_ref()
+    }
+
+    /// Free buffer slots the producer can fill without a ready consumer
+    pub fn credits(&self) -> usize {
+        self.credits - self.buffer.len()
+    }
+
+    /// Number of accepted items the consumer has not taken yet
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Get the transfer counters
+    pub fn stats(&self) -> &ChannelStats {
+        &self.stats
+    }
+}
+
+impl<T: MemoryData> MemoryData for Channel<T> {
+    fn merge(&mut self, other: Self) {
+        self.sending = self.sending.take().or(other.sending);
+        self.ready |= other.ready;
+    }
+}
+
+impl<T: MemoryData> Cycle for Channel<T> {
+    type Output = bool;
+
+    fn cycle(&mut self) -> Option<Self::Output> {
+        if let Some(item) = self.sending.take() {
+            self.offer = Some(item);
+            self.stats.offered += 1;
+        }
+        self.accepted = false;
+        self.received = None;
+        if std::mem::take(&mut self.ready) {
+            self.received = match self.buffer.pop_front() {
+                Some(item) => Some(item),
+                None => {
+                    self.accepted = self.offer.is_some();
+                    self.offer.take()
+                }
+            };
+        }
+        if self.offer.is_some() && self.buffer.len() < self.credits {
+            self.buffer.extend(self.offer.take());
+            self.accepted = true;
+        }
+        if self.received.is_some() {
+            self.stats.transfers += 1;
+        }
+        if self.offer.is_some() {
+            self.stats.stalls += 1;
+        }
+        self.stats.max_buffered = self.stats.max_buffered.max(self.buffer.len());
+        Some(self.received.is_some())
+    }
+}
+
+impl<T: MemoryData> MemoryComponent for Channel<T> {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("input".to_string(), PortType::Input),
+            ("output".to_string(), PortType::Output),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offer_waits_for_ready_consumer() {
+        let mut channel = Channel::<i64>::ready_valid();
+        assert!(channel.send(1).is_ok());
+        channel.cycle();
+        assert!(!channel.accepted() && !channel.can_send());
+        assert_eq!(channel.send(2), Err(2));
+
+        // Producer and consumer copies of the same snapshot merge
+        let mut consumer = channel.clone();
+        consumer.set_ready();
+        channel.merge(consumer);
+        channel.cycle();
+        assert!(channel.accepted() && channel.can_send());
+        assert_eq!(channel.received(), Some(&1));
+        assert_eq!((channel.stats().transfers, channel.stats().stalls), (1, 1));
+    }
+
+    #[test]
+    fn test_credits_accept_without_ready_consumer() {
+        let mut channel = Channel::<i64>::with_credits(1);
+        channel.send(1).unwrap();
+        channel.cycle();
+        assert!(channel.accepted());
+        assert_eq!(channel.credits(), 0);
+        channel.send(2).unwrap();
+        channel.cycle();
+        assert!(!channel.accepted());
+        channel.set_ready();
+        channel.cycle();
+        assert_eq!(channel.received(), Some(&1));
+        assert!(channel.accepted());
+        assert_eq!(channel.buffered(), 1);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::core::components::state::MemoryData;
+use crate::core::components::traits::{Cycle, MemoryComponent};
+use crate::core::components::types::PortType;
+use std::collections::{BTreeMap, VecDeque};
+
+/// Occupancy and throughput counters of a `Fifo`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FifoStats {
+    /// Items accepted into the queue
+    pub pushed: u64,
+    /// Items handed to consumers
+    pub popped: u64,
+    /// Pushes dropped because the queue was full
+    pub rejected: u64,
+    /// Pop requests made while the queue was empty
+    pub unserved_pops: u64,
+    /// Largest number of items held after a cycle
+    pub max_occupancy: usize,
+    /// Sum of the occupancy after each cycle
+    pub occupancy_sum: u64,
+    /// Number of cycles resolved
+    pub cycles: u64,
+}
+
+impl FifoStats {
+    /// Mean number of items held per cycle
+    pub fn mean_occupancy(&self) -> f64 {
+        if self.cycles == 0 {
+            0.0
+        } else {
+            self.occupancy_sum as f64 / self.cycles as f64
+        }
+    }
+}
+
+/// Bounded queue of items stored at a memory address
+///
+/// Producers and consumers read the queue as it was at the end of the
+/// previous cycle and record requests on their copy before writing it back:
+/// `push` to enqueue an item and `request_pop` to claim the front item.
+/// Copies written by several components in one cycle are merged, and
+/// `cycle()` resolves the pops first, then the pushes, in the order the
+/// writes were applied, which is deterministic in every concurrency mode.
+/// Claimed items are handed over in the next cycle through `delivered`, and
+/// pushes that find the queue full are rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fifo<T> {
+    capacity: usize,
+    items: VecDeque<T>,
+    pushes: Vec<T>,
+    pops: Vec<String>,
+    delivered: BTreeMap<String, Vec<T>>,
+    stats: FifoStats,
+}
+
+impl<T: MemoryData> Fifo<T> {
+    /// Create an empty queue holding at most `capacity` items
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            items: VecDeque::new(),
+            pushes: Vec::new(),
+            pops: Vec::new(),
+            delivered: BTreeMap::new(),
+            stats: FifoStats::default(),
+        }
+    }
+
+    /// Maximum number of items held
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of items held
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check if the queue holds no items
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Check if the queue is at capacity
+    pub fn is_full(&self) -> bool {
+        self.items.len() >= self.capacity
+    }
+
+    /// Get the item at the front of the queue
+    pub fn front(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Iterate over the held items, front first
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+
+    /// Request to enqueue an item at the end of this cycle
+    pub fn push(&mut self, item: T) {
+        self.pushes.push(item);
+    }
+
+    /// Request the front item for `consumer`, handed over in the next cycle
+    pub fn request_pop(&mut self, consumer: &str) {
+        self.pops.push(consumer.to_string());
+    }
+
+    /// Items handed to `consumer` by the previous cycle's pop requests
+    pub fn delivered(&self, consumer: &str) -> &[T] {
+        self.delivered.get(consumer).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Number of push requests recorded this cycle
+    pub fn pending_pushes(&self) -> usize {
+        self.pushes.len()
+    }
+
+    /// Number of pop requests recorded this cycle
+    pub fn pending_pops(&self) -> usize {
+        self.pops.len()
+    }
+
+    /// Get the occupancy and throughput counters
+    pub fn stats(&self) -> &FifoStats {
+        &self.stats
+    }
+}
+
+impl<T: MemoryData> MemoryData for Fifo<T> {
+    fn merge(&mut self, other: Self) {
+        self.pushes.extend(other.pushes);
+        self.pops.extend(other.pops);
+    }
+}
+
+impl<T: MemoryData> Cycle for Fifo<T> {
+    type Output = usize;
+
+    fn cycle(&mut self) -> Option<Self::Output> {
+        self.delivered.clear();
+        for consumer in std::mem::take(&mut self.pops) {
+            match self.items.pop_front() {
+                Some(item) => {
+                    self.delivered.entry(consumer).or_default().push(item);
+                    self.stats.popped += 1;
+                }
+                None => self.stats.unserved_pops += 1,
+            }
+        }
+        for item in std::mem::take(&mut self.pushes) {
+            if self.items.len() < self.capacity {
+                self.items.push_back(item);
+                self.stats.pushed += 1;
+            } else {
+                self.stats.rejected += 1;
+            }
+        }
+        self.stats.cycles += 1;
+        self.stats.occupancy_sum += self.items.len() as u64;
+        self.stats.max_occupancy = self.stats.max_occupancy.max(self.items.len());
+        Some(self.items.len())
+    }
+}
+
+impl<T: MemoryData> MemoryComponent for Fifo<T> {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("input".to_string(), PortType::Input),
+            ("output".to_string(), PortType::Output),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Order(u32);
+
+    impl MemoryData for Order {}
+
+    #[test]
+    fn test_requests_resolve_pops_before_pushes() {
+        let mut fifo = Fifo::new(2);
+        fifo.push(Order(1));
+        fifo.push(Order(2));
+        fifo.push(Order(3));
+        fifo.request_pop("early");
+        assert_eq!(fifo.cycle(), Some(2));
+        assert_eq!(fifo.delivered("early"), &[] as &[Order]);
+        assert_eq!((fifo.stats().rejected, fifo.stats().unserved_pops), (1, 1));
+
+        // Two writers' copies of the same snapshot merge their requests
+        let mut first = fifo.clone();
+        first.request_pop("a");
+        first.push(Order(4));
+        let mut second = fifo.clone();
+        second.request_pop("b");
+        first.merge(second);
+        fifo = first;
+        fifo.cycle();
+        assert_eq!(fifo.delivered("a"), &[Order(1)]);
+        assert_eq!(fifo.delivered("b"), &[Order(2)]);
+        assert_eq!(fifo.iter().cloned().collect::<Vec<_>>(), vec![Order(4)]);
+        assert_eq!(fifo.stats().max_occupancy, 2);
+        assert_eq!(fifo.stats().mean_occupancy(), 1.5);
+    }
+}
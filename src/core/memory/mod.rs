@@ -1,8 +1,14 @@
 pub mod proxy;
 pub mod errors;
 pub mod delta;
+pub mod fifo;
+pub mod channel;
+pub mod append_log;
 
 // Re-export commonly used types
 pub use proxy::MemoryProxy;
 pub use errors::MemoryError;
-pub use delta::{MemoryDelta, MemoryWrite};
\ No newline at end of file
+pub use delta::{MemoryDelta, MemoryWrite};
+pub use fifo::{Fifo, FifoStats};
+pub use channel::{Channel, ChannelStats, CHANNEL_ADDRESS};
+pub use append_log::AppendLog;
\ No newline at end of file
@@ -1,7 +1,8 @@
 use crate::core::components::state::MemoryData;
-use crate::core::components::module::MemoryModuleTrait;
+use crate::core::components::module::{MemoryModuleTrait, MemoryStats};
 use crate::core::types::ComponentId;
 use crate::core::memory::delta::{MemoryDelta, MemoryWrite};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::sync::mpsc;
 
@@ -24,6 +25,8 @@ pub struct MemoryProxy<'a> {
     memory_delta: Option<MemoryDelta>,
     /// Channel sender for memory writes (for channel-based memory synchronization)
     memory_write_sender: Option<mpsc::Sender<MemoryWrite>>,
+    /// Read/write counts per memory component made through this proxy
+    access_stats: RefCell<HashMap<ComponentId, MemoryStats>>,
 }
 
 impl<'a> MemoryProxy<'a> {
@@ -41,6 +44,7 @@ impl<'a> MemoryProxy<'a> {
             memory_components_subset: None,
             memory_delta: None,
             memory_write_sender: None,
+            access_stats: RefCell::new(HashMap::new()),
         }
     }
 
@@ -60,6 +64,7 @@ impl<'a> MemoryProxy<'a> {
             memory_components_subset: Some(memory_subset.to_vec()),
             memory_delta: None,
             memory_write_sender: None,
+            access_stats: RefCell::new(HashMap::new()),
         }
     }
     
@@ -79,6 +84,7 @@ impl<'a> MemoryProxy<'a> {
             memory_components_subset: Some(memory_subset.to_vec()),
             memory_delta: None,
             memory_write_sender: None,
+            access_stats: RefCell::new(HashMap::new()),
         }
     }
 
@@ -98,6 +104,7 @@ impl<'a> MemoryProxy<'a> {
             memory_components_subset: Some(memory_subset.to_vec()),
             memory_delta: Some(MemoryDelta::new()),
             memory_write_sender: None,
+            access_stats: RefCell::new(HashMap::new()),
         }
     }
 
@@ -118,6 +125,7 @@ impl<'a> MemoryProxy<'a> {
             memory_components_subset: Some(memory_subset.to_vec()),
             memory_delta: None,
             memory_write_sender: Some(memory_write_sender),
+            access_stats: RefCell::new(HashMap::new()),
         }
     }
 
@@ -144,6 +152,7 @@ impl<'a> MemoryProxy<'a> {
         };
         
         if let Some(memory_module) = memory_module {
+            self.access_stats.borrow_mut().entry(mem_id.clone()).or_default().increment_reads();
             if let Some(data_box) = memory_module.read_any(address) {
                 // Try to downcast to the requested type
                 if let Ok(typed_data) = data_box.downcast::<T>() {
@@ -184,6 +193,7 @@ impl<'a> MemoryProxy<'a> {
             
             sender.send(memory_write)
                 .map_err(|e| format!("Failed to send memory write through channel: {}", e))?;
+            self.access_stats.get_mut().entry(mem_id.clone()).or_default().increment_writes();
             
             return Ok(());
         }
@@ -202,6 +212,7 @@ impl<'a> MemoryProxy<'a> {
         if let Some(memory_module) = memory_module {
             let data_box: Box<dyn std::any::Any + Send> = Box::new(data);
             if memory_module.write_any(address, data_box) {
+                self.access_stats.get_mut().entry(mem_id.clone()).or_default().increment_writes();
                 // If delta tracking is enabled, record the write
                 if let Some(ref mut delta) = self.memory_delta {
                     delta.record_write(
@@ -268,6 +279,11 @@ impl<'a> MemoryProxy<'a> {
         self.owned_memory_modules.unwrap_or_default()
     }
 
+    /// Take the read/write counts made through this proxy, keyed by memory component
+    pub fn take_access_stats(&mut self) -> HashMap<ComponentId, MemoryStats> {
+        std::mem::take(self.access_stats.get_mut())
+    }
+
     /// Get a reference to the owned memory modules
     /// This method allows access to the memory modules without consuming the proxy
     pub fn get_owned_memory_modules(&self) -> Option<&HashMap<ComponentId, Box<dyn MemoryModuleTrait>>> {
@@ -5,6 +5,16 @@ pub mod connections;
 pub mod memory;
 pub mod execution;
 pub mod builder;
+pub mod stimulus;
+pub mod probes;
+pub mod stats;
+pub mod random;
+pub mod experiments;
+pub mod verification;
+pub mod replay;
+pub mod debugger;
+pub mod queueing;
+pub mod entities;
 
 // Core types (keep at root level)
 pub mod types;
@@ -21,4 +31,4 @@ pub use components::{
 pub use connections::{ConnectionManager, ConnectionValidator, PortValidator};
 pub use memory::{MemoryProxy, MemoryError};
 pub use execution::{CycleEngine, SimulationEngine, SimulationConfig, ConcurrencyMode};
-pub use builder::{Simulation, SimulationExt};
\ No newline at end of file
+pub use builder::{Simulation, SimulationExt, DiagnosticsReport, DiagnosticsConfig, Diagnostic, DiagnosticKind, Severity};
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub mod probe;
+pub mod time_series;
+
+// Re-export commonly used types
+pub use probe::{Probe, ProbeTarget};
+pub use time_series::{TimeSeries, Sample, ProbeRecorder};
@@ -0,0 +1,116 @@
+use crate::core::types::{ComponentId, OutputPort};
+use std::any::Any;
+
+/// Projection from a type-erased payload to a sampled value
+pub type Projection = Box<dyn Fn(&dyn Any) -> Option<f64> + Send + Sync>;
+
+/// What a probe observes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProbeTarget {
+    /// Last event emitted on a component output port
+    Output { component_id: ComponentId, port: String },
+    /// Value stored at an address of a memory module (snapshot after the cycle)
+    Memory { memory_id: ComponentId, address: String },
+}
+
+/// Observer attached to an output port or memory address
+///
+/// A probe is sampled at the end of every `interval`-th cycle. The payload
+/// is turned into an `f64` by its projection; without an explicit
+/// projection, common numeric types and `bool` are converted directly.
+pub struct Probe {
+    /// Unique name of the time series this probe records into
+    pub name: String,
+    /// Observed output port or memory address
+    pub target: ProbeTarget,
+    /// Sample every N cycles (1 = every cycle)
+    pub interval: u64,
+    projection: Projection,
+}
+
+impl Probe {
+    /// Create a probe on a component output port
+    pub fn output(name: &str, port: OutputPort) -> Self {
+        Self {
+            name: name.to_string(),
+            target: ProbeTarget::Output {
+                component_id: port.component_id().clone(),
+                port: port.port_name().to_string(),
+            },
+            interval: 1,
+            projection: Box::new(numeric_projection),
+        }
+    }
+
+    /// Create a probe on an address of a memory module
+    pub fn memory(name: &str, memory_id: ComponentId, address: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            target: ProbeTarget::Memory {
+                memory_id,
+                address: address.to_string(),
+            },
+            interval: 1,
+            projection: Box::new(numeric_projection),
+        }
+    }
+
+    /// Project the observed payload of type `T` to an `f64`
+    ///
+    /// Samples whose payload is not a `T` are skipped.
+    pub fn with_projection<T, F>(mut self, projection: F) -> Self
+    where
+        T: 'static,
+        F: Fn(&T) -> f64 + Send + Sync + 'static,
+    {
+        self.projection = Box::new(move |value: &dyn Any| value.downcast_ref::<T>().map(&projection));
+        self
+    }
+
+    /// Sample every `interval` cycles instead of every cycle
+    pub fn every(mut self, interval: u64) -> Self {
+        self.interval = interval.max(1);
+        self
+    }
+
+    /// Check if this probe samples in the given cycle
+    pub fn samples_at(&self, cycle: u64) -> bool {
+        cycle.is_multiple_of(self.interval)
+    }
+
+    /// Apply the projection to a type-erased payload
+    pub fn project(&self, value: &dyn Any) -> Option<f64> {
+        (self.projection)(value)
+    }
+}
+
+impl std::fmt::Debug for Probe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Probe")
+            .field("name", &self.name)
+            .field("target", &self.target)
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
+
+/// Default projection: convert common numeric payloads to `f64`
+fn numeric_projection(value: &dyn Any) -> Option<f64> {
+    if let Some(v) = value.downcast_ref::<f64>() {
+        Some(*v)
+    } else if let Some(v) = value.downcast_ref::<f32>() {
+        Some(*v as f64)
+    } else if let Some(v) = value.downcast_ref::<i64>() {
+        Some(*v as f64)
+    } else if let Some(v) = value.downcast_ref::<i32>() {
+        Some(*v as f64)
+    } else if let Some(v) = value.downcast_ref::<u64>() {
+        Some(*v as f64)
+    } else if let Some(v) = value.downcast_ref::<u32>() {
+        Some(*v as f64)
+    } else if let Some(v) = value.downcast_ref::<usize>() {
+        Some(*v as f64)
+    } else {
+        value.downcast_ref::<bool>().map(|v| if *v { 1.0 } else { 0.0 })
+    }
+}
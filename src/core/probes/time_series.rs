@@ -0,0 +1,187 @@
+use crate::core::probes::probe::Probe;
+use crate::core::stats::{BatchMeans, batch_means, mser};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::Path;
+
+/// A single probe sample
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    pub cycle: u64,
+    pub value: f64,
+}
+
+/// In-memory time series recorded by one probe
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimeSeries {
+    pub name: String,
+    pub samples: Vec<Sample>,
+}
+
+impl TimeSeries {
+    /// Create an empty time series
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            samples: Vec::new(),
+        }
+    }
+
+    /// Append a sample
+    pub fn push(&mut self, cycle: u64, value: f64) {
+        self.samples.push(Sample { cycle, value });
+    }
+
+    /// Get all sampled values in cycle order
+    pub fn values(&self) -> Vec<f64> {
+        self.samples.iter().map(|s| s.value).collect()
+    }
+
+    /// Get the most recent sample
+    pub fn last(&self) -> Option<&Sample> {
+        self.samples.last()
+    }
+
+    /// Get the number of samples
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check if no samples were recorded
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Copy of the series keeping only samples at or after `cycle`
+    pub fn from_cycle(&self, cycle: u64) -> TimeSeries {
+        TimeSeries {
+            name: self.name.clone(),
+            samples: self.samples.iter().filter(|s| s.cycle >= cycle).copied().collect(),
+        }
+    }
+
+    /// First cycle after the warm-up period detected by MSER-5, if enough samples exist
+    pub fn mser_warmup_end(&self) -> Option<u64> {
+        let result = mser(&self.values(), 5)?;
+        self.samples.get(result.truncation).map(|s| s.cycle)
+    }
+
+    /// Batch-means analysis of the sampled values
+    pub fn batch_means(&self, batches: usize) -> Option<BatchMeans> {
+        batch_means(&self.values(), batches)
+    }
+
+    /// Write the series as `cycle,<name>` CSV
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        let mut file = std::fs::File::create(path)
+            .map_err(|e| format!("Failed to create '{}': {}", path.display(), e))?;
+        writeln!(file, "cycle,{}", self.name)
+            .and_then(|_| self.samples.iter().try_for_each(|s| writeln!(file, "{},{}", s.cycle, s.value)))
+            .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+    }
+}
+
+/// Registered probes and the time series they record
+#[derive(Debug, Default)]
+pub struct ProbeRecorder {
+    probes: Vec<Probe>,
+    series: BTreeMap<String, TimeSeries>,
+}
+
+impl ProbeRecorder {
+    /// Create an empty recorder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a probe; names must be unique
+    pub fn add(&mut self, probe: Probe) -> Result<(), String> {
+        if self.series.contains_key(&probe.name) {
+            return Err(format!("Probe '{}' is already registered", probe.name));
+        }
+        self.series.insert(probe.name.clone(), TimeSeries::new(&probe.name));
+        self.probes.push(probe);
+        Ok(())
+    }
+
+    /// Get all registered probes
+    pub fn probes(&self) -> &[Probe] {
+        &self.probes
+    }
+
+    /// Check if any probes are registered
+    pub fn is_empty(&self) -> bool {
+        self.probes.is_empty()
+    }
+
+    /// Record a sample for a probe by name
+    pub fn record(&mut self, name: &str, cycle: u64, value: f64) {
+        if let Some(series) = self.series.get_mut(name) {
+            series.push(cycle, value);
+        }
+    }
+
+    /// Get the time series recorded by a probe
+    pub fn series(&self, name: &str) -> Option<&TimeSeries> {
+        self.series.get(name)
+    }
+
+    /// Get all recorded time series, ordered by name
+    pub fn all_series(&self) -> impl Iterator<Item = &TimeSeries> {
+        self.series.values()
+    }
+
+    /// Discard samples recorded after `cycle`
+    pub fn truncate_after(&mut self, cycle: u64) {
+        for series in self.series.values_mut() {
+            series.samples.retain(|sample| sample.cycle <= cycle);
+        }
+    }
+
+    /// Discard all recorded samples, keeping the probes
+    pub fn clear_samples(&mut self) {
+        for series in self.series.values_mut() {
+            series.samples.clear();
+        }
+    }
+
+    /// Write all series as one wide CSV: `cycle,<probe1>,<probe2>,...`
+    ///
+    /// Cells are left empty for probes that have no sample in a cycle.
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        let mut file = std::fs::File::create(path)
+            .map_err(|e| format!("Failed to create '{}': {}", path.display(), e))?;
+        file.write_all(self.to_csv().as_bytes())
+            .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+    }
+
+    /// Render all series as one wide CSV string
+    pub fn to_csv(&self) -> String {
+        let cycles: BTreeSet<u64> = self.series.values()
+            .flat_map(|s| s.samples.iter().map(|sample| sample.cycle))
+            .collect();
+        let columns: Vec<BTreeMap<u64, f64>> = self.series.values()
+            .map(|s| s.samples.iter().map(|sample| (sample.cycle, sample.value)).collect())
+            .collect();
+
+        let mut csv = String::from("cycle");
+        for name in self.series.keys() {
+            csv.push(',');
+            csv.push_str(name);
+        }
+        csv.push('\n');
+        for cycle in cycles {
+            csv.push_str(&cycle.to_string());
+            for column in &columns {
+                csv.push(',');
+                if let Some(value) = column.get(&cycle) {
+                    csv.push_str(&value.to_string());
+                }
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
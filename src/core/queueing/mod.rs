@@ -0,0 +1,6 @@
+pub mod resource;
+pub mod station;
+
+// Re-export commonly used types
+pub use resource::{Resource, ResourceStats, Discipline, ServiceTime};
+pub use station::{Station, StationResource, RESOURCE_ADDRESS};
@@ -0,0 +1,416 @@
+use crate::core::components::state::MemoryData;
+use crate::core::components::traits::{Cycle, MemoryComponent};
+use crate::core::components::types::PortType;
+use crate::core::random::ComponentRng;
+
+/// Order in which waiting customers are served
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Discipline {
+    /// Earliest arrival first
+    Fifo,
+    /// Latest arrival first
+    Lifo,
+    /// Highest priority first, earliest arrival on ties
+    Priority,
+}
+
+/// Distribution of service times, in cycles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ServiceTime {
+    /// Always the given number of cycles
+    Fixed(u64),
+    /// Uniform over `[low, high]`
+    Uniform(u64, u64),
+    /// Exponential with the given mean, rounded up
+    Exponential(f64),
+}
+
+impl ServiceTime {
+    /// Draw a service time of at least one cycle
+    pub fn sample(&self, rng: &mut ComponentRng) -> Result<u64, String> {
+        let cycles = match *self {
+            ServiceTime::Fixed(cycles) => cycles,
+            ServiceTime::Uniform(low, high) => rng.uniform_int(low as i64, high as i64)? as u64,
+            ServiceTime::Exponential(mean) => {
+                if mean <= 0.0 {
+                    return Err(format!("Invalid exponential mean service time {}", mean));
+                }
+                rng.exponential(1.0 / mean)?.ceil() as u64
+            }
+        };
+        Ok(cycles.max(1))
+    }
+}
+
+/// Customer waiting for or holding a server
+#[derive(Debug, Clone, PartialEq)]
+struct Customer<T> {
+    item: T,
+    priority: i64,
+    remaining: u64,
+    patience: Option<u64>,
+    arrived: u64,
+    started: Option<u64>,
+    seq: u64,
+}
+
+/// Utilization and wait-time counters of a `Resource`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceStats {
+    /// Arrivals admitted or balked
+    pub arrivals: u64,
+    /// Customers that completed service
+    pub served: u64,
+    /// Arrivals turned away by a full queue
+    pub balked: u64,
+    /// Customers that left the queue after running out of patience
+    pub reneged: u64,
+    /// Customers pushed out of a server by a higher priority one
+    pub preemptions: u64,
+    /// Customers that started service
+    pub started: u64,
+    /// Total cycles from arrival to first service
+    pub total_wait: u64,
+    /// Longest cycles from arrival to first service
+    pub max_wait: u64,
+    /// Total cycles from arrival to completion of served customers
+    pub total_sojourn: u64,
+    /// Cycles spent serving, summed over servers
+    pub busy_server_cycles: u64,
+    /// Waiting customers summed over cycles
+    pub queue_length_sum: u64,
+    /// Most customers waiting after a cycle
+    pub max_queue_length: usize,
+    /// Number of cycles resolved
+    pub cycles: u64,
+}
+
+/// Multi-server queueing resource stored at a memory address
+///
+/// Components read the resource as it was at the end of the previous cycle,
+/// add arrivals with `request` and write it back; arrivals written by several
+/// components in one cycle are merged. At the end of each cycle the resource
+/// advances service by one cycle, admits the arrivals (balking those that find
+/// the queue full), starts waiting customers on free servers in discipline
+/// order (preempting lower priority customers if enabled, who resume later)
+/// and removes waiting customers whose patience ran out. Items that left are
+/// visible through `departures`, `balked` and `reneged` in the next cycle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resource<T> {
+    servers: usize,
+    queue_capacity: Option<usize>,
+    discipline: Discipline,
+    preemptive: bool,
+    service: ServiceTime,
+    now: u64,
+    next_seq: u64,
+    requests: Vec<Customer<T>>,
+    waiting: Vec<Customer<T>>,
+    in_service: Vec<Customer<T>>,
+    departures: Vec<T>,
+    balked: Vec<T>,
+    reneged: Vec<T>,
+    stats: ResourceStats,
+}
+
+impl<T: MemoryData> Resource<T> {
+    /// FIFO resource with `servers` servers, an unbounded queue and unit service time
+    pub fn new(servers: usize) -> Self {
+        Self {
+            servers,
+            queue_capacity: None,
+            discipline: Discipline::Fifo,
+            preemptive: false,
+            service: ServiceTime::Fixed(1),
+            now: 0,
+            next_seq: 0,
+            requests: Vec::new(),
+            waiting: Vec::new(),
+            in_service: Vec::new(),
+            departures: Vec::new(),
+            balked: Vec::new(),
+            reneged: Vec::new(),
+            stats: ResourceStats::default(),
+        }
+    }
+
+    /// Set the order waiting customers are served in
+    pub fn with_discipline(mut self, discipline: Discipline) -> Self {
+        self.discipline = discipline;
+        self
+    }
+
+    /// Balk arrivals that find `capacity` customers waiting and no free server
+    pub fn with_queue_capacity(mut self, capacity: usize) -> Self {
+        self.queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Let higher priority customers take a server from lower priority ones
+    pub fn with_preemption(mut self) -> Self {
+        self.preemptive = true;
+        self
+    }
+
+    /// Set the service-time distribution drawn by `sample_service`
+    pub fn with_service(mut self, service: ServiceTime) -> Self {
+        self.service = service;
+        self
+    }
+
+    /// Draw a service time from the resource's distribution
+    pub fn sample_service(&self, rng: &mut ComponentRng) -> Result<u64, String> {
+        self.service.sample(rng)
+    }
+
+    /// Add an arrival needing `service` cycles, with priority 0 and unlimited patience
+    pub fn request(&mut self, item: T, service: u64) {
+        self.request_with(item, service, 0, None);
+    }
+
+    /// Add an arrival with a priority and the number of cycles it waits before reneging
+    pub fn request_with(&mut self, item: T, service: u64, priority: i64, patience: Option<u64>) {
+        self.requests.push(Customer {
+            item,
+            priority,
+            remaining: service.max(1),
+            patience,
+            arrived: 0,
+            started: None,
+            seq: 0,
+        });
+    }
+
+    /// Number of servers
+    pub fn servers(&self) -> usize {
+        self.servers
+    }
+
+    /// Number of servers busy
+    pub fn busy(&self) -> usize {
+        self.in_service.len()
+    }
+
+    /// Number of customers waiting
+    pub fn queue_length(&self) -> usize {
+        self.waiting.len()
+    }
+
+    /// Items being served
+    pub fn serving(&self) -> impl Iterator<Item = &T> {
+        self.in_service.iter().map(|customer| &customer.item)
+    }
+
+    /// Items that completed service at the end of the previous cycle
+    pub fn departures(&self) -> &[T] {
+        &self.departures
+    }
+
+    /// Arrivals turned away at the end of the previous cycle
+    pub fn balked(&self) -> &[T] {
+        &self.balked
+    }
+
+    /// Customers that gave up waiting at the end of the previous cycle
+    pub fn reneged(&self) -> &[T] {
+        &self.reneged
+    }
+
+    /// Get the utilization and wait-time counters
+    pub fn stats(&self) -> &ResourceStats {
+        &self.stats
+    }
+
+    /// Fraction of server capacity spent serving
+    pub fn utilization(&self) -> f64 {
+        if self.stats.cycles == 0 || self.servers == 0 {
+            0.0
+        } else {
+            self.stats.busy_server_cycles as f64 / (self.stats.cycles * self.servers as u64) as f64
+        }
+    }
+
+    /// Mean cycles from arrival to first service
+    pub fn mean_wait(&self) -> f64 {
+        ratio(self.stats.total_wait, self.stats.started)
+    }
+
+    /// Mean cycles from arrival to completion of served customers
+    pub fn mean_sojourn(&self) -> f64 {
+        ratio(self.stats.total_sojourn, self.stats.served)
+    }
+
+    /// Mean number of customers waiting per cycle
+    pub fn mean_queue_length(&self) -> f64 {
+        ratio(self.stats.queue_length_sum, self.stats.cycles)
+    }
+
+    /// Index of the waiting customer to serve next
+    fn next_waiting(&self) -> Option<usize> {
+        let positions = 0..self.waiting.len();
+        match self.discipline {
+            Discipline::Fifo => positions.min_by_key(|i| self.waiting[*i].seq),
+            Discipline::Lifo => positions.max_by_key(|i| self.waiting[*i].seq),
+            Discipline::Priority => positions.max_by_key(|i| (self.waiting[*i].priority, std::cmp::Reverse(self.waiting[*i].seq))),
+        }
+    }
+
+    /// Index of the customer in service a higher priority one would preempt
+    fn preemption_victim(&self) -> Option<usize> {
+        (0..self.in_service.len()).min_by_key(|i| (self.in_service[*i].priority, std::cmp::Reverse(self.in_service[*i].seq)))
+    }
+
+    fn start(&mut self, mut customer: Customer<T>) {
+        if customer.started.is_none() {
+            let wait = self.now - customer.arrived;
+            self.stats.started += 1;
+            self.stats.total_wait += wait;
+            self.stats.max_wait = self.stats.max_wait.max(wait);
+            customer.started = Some(self.now);
+        }
+        self.in_service.push(customer);
+    }
+}
+
+fn ratio(total: u64, count: u64) -> f64 {
+    if count == 0 { 0.0 } else { total as f64 / count as f64 }
+}
+
+impl<T: MemoryData> MemoryData for Resource<T> {
+    fn merge(&mut self, other: Self) {
+        self.requests.extend(other.requests);
+    }
+}
+
+impl<T: MemoryData> Cycle for Resource<T> {
+    type Output = usize;
+
+    fn cycle(&mut self) -> Option<Self::Output> {
+        self.now += 1;
+        self.departures.clear();
+        self.balked.clear();
+        self.reneged.clear();
+
+        // Advance service
+        self.stats.busy_server_cycles += self.in_service.len() as u64;
+        let mut serving = Vec::with_capacity(self.in_service.len());
+        for mut customer in std::mem::take(&mut self.in_service) {
+            customer.remaining -= 1;
+            if customer.remaining == 0 {
+                self.stats.served += 1;
+                self.stats.total_sojourn += self.now - customer.arrived;
+                self.departures.push(customer.item);
+            } else {
+                serving.push(customer);
+            }
+        }
+        self.in_service = serving;
+
+        // Admit arrivals
+        for mut customer in std::mem::take(&mut self.requests) {
+            self.stats.arrivals += 1;
+            let free = self.servers.saturating_sub(self.in_service.len());
+            if self.queue_capacity.is_some_and(|capacity| self.waiting.len() >= capacity + free) {
+                self.stats.balked += 1;
+                self.balked.push(customer.item);
+                continue;
+            }
+            customer.arrived = self.now;
+            customer.seq = self.next_seq;
+            self.next_seq += 1;
+            self.waiting.push(customer);
+        }
+
+        // Fill free servers, then preempt lower priority customers
+        while self.in_service.len() < self.servers {
+            let Some(index) = self.next_waiting() else { break };
+            let customer = self.waiting.remove(index);
+            self.start(customer);
+        }
+        if self.preemptive && self.discipline == Discipline::Priority {
+            while let (Some(next), Some(victim)) = (self.next_waiting(), self.preemption_victim()) {
+                if self.waiting[next].priority <= self.in_service[victim].priority {
+                    break;
+                }
+                let preempted = self.in_service.swap_remove(victim);
+                let customer = self.waiting.remove(next);
+                self.waiting.push(preempted);
+                self.stats.preemptions += 1;
+                self.start(customer);
+            }
+        }
+
+        // Renege customers that ran out of patience
+        let now = self.now;
+        let (stay, leave): (Vec<_>, Vec<_>) = std::mem::take(&mut self.waiting).into_iter()
+            .partition(|customer| customer.started.is_some() || customer.patience.is_none_or(|patience| now - customer.arrived < patience));
+        self.waiting = stay;
+        self.stats.reneged += leave.len() as u64;
+        self.reneged.extend(leave.into_iter().map(|customer| customer.item));
+
+        self.stats.cycles += 1;
+        self.stats.queue_length_sum += self.waiting.len() as u64;
+        self.stats.max_queue_length = self.stats.max_queue_length.max(self.waiting.len());
+        Some(self.waiting.len())
+    }
+}
+
+impl<T: MemoryData> MemoryComponent for Resource<T> {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("input".to_string(), PortType::Input),
+            ("output".to_string(), PortType::Output),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(resource: &mut Resource<i64>, cycles: usize) -> Vec<i64> {
+        let mut departures = Vec::new();
+        for _ in 0..cycles {
+            resource.cycle();
+            departures.extend_from_slice(resource.departures());
+        }
+        departures
+    }
+
+    #[test]
+    fn test_disciplines_order_service() {
+        for (discipline, expected) in [(Discipline::Fifo, vec![1, 2, 3, 4]), (Discipline::Lifo, vec![4, 3, 2, 1]), (Discipline::Priority, vec![3, 4, 1, 2])] {
+            let mut resource = Resource::new(1).with_discipline(discipline);
+            for (item, priority) in [(1, 0), (2, 0), (3, 5), (4, 1)] {
+                resource.request_with(item, 2, priority, None);
+            }
+            assert_eq!(run(&mut resource, 9), expected);
+            let stats = resource.stats();
+            assert_eq!((stats.started, stats.total_wait, stats.max_wait), (4, 12, 6));
+            assert_eq!(resource.utilization(), 8.0 / 9.0);
+        }
+    }
+
+    #[test]
+    fn test_preemption_balking_and_reneging() {
+        let mut resource = Resource::new(1).with_discipline(Discipline::Priority).with_preemption().with_queue_capacity(1);
+        resource.request(1, 3);
+        resource.cycle();
+        // Priority 9 preempts item 1; item 3 then finds the queue full and balks
+        resource.request_with(2, 1, 9, None);
+        resource.request(3, 1);
+        resource.cycle();
+        assert_eq!(resource.balked(), &[3]);
+        assert_eq!(resource.stats().preemptions, 1);
+        // Item 1 resumes with its remaining two cycles of service
+        assert_eq!(run(&mut resource, 3), vec![2, 1]);
+        assert_eq!(resource.stats().started, 2);
+
+        let mut resource = Resource::new(1);
+        resource.request(1, 5);
+        resource.request_with(2, 1, 0, Some(2));
+        assert_eq!(run(&mut resource, 3), Vec::<i64>::new());
+        assert_eq!(resource.reneged(), &[2]);
+        assert_eq!(resource.stats().reneged, 1);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::*;
+use crate::core::queueing::resource::Resource;
+use crate::core::values::traits::{EventInputs, EventOutputs};
+use crate::core::values::typed_value::TypedValue;
+
+/// Memory address of a `Station`'s resource
+pub const RESOURCE_ADDRESS: &str = "resource";
+
+/// Resource holding values of any type, as used by `Station`
+pub type StationResource = Resource<TypedValue>;
+
+/// Queues each value on `arrival` at the `StationResource` on its `resource` port
+///
+/// The service time is drawn from the resource's distribution with the
+/// station's random stream. Optional `priority` (`i64`) and `patience`
+/// (`u64`) inputs apply to the arrival in the same cycle. The number of items
+/// that departed, balked or reneged in the previous cycle is emitted as a
+/// `u64` on `departed`, `balked` and `reneged` when non-zero; the items
+/// themselves are read from the resource.
+pub struct Station;
+
+impl_component!(Station, "Station", {
+    inputs: [arrival, priority, patience],
+    outputs: [departed, balked, reneged],
+    memory: [resource],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "resource", RESOURCE_ADDRESS, resource: StationResource);
+        for (port, count) in [("departed", resource.departures().len()), ("balked", resource.balked().len()), ("reneged", resource.reneged().len())] {
+            if count > 0 {
+                outputs.set(port, count as u64)?;
+            }
+        }
+        if let Ok(event) = ctx.inputs.get_event("arrival") {
+            let service = resource.sample_service(ctx.rng)?;
+            let priority = ctx.inputs.get::<i64>("priority").unwrap_or(0);
+            let patience = ctx.inputs.get::<u64>("patience").ok();
+            resource.request_with(event.payload.clone(), service, priority, patience);
+            memory_write!(ctx, "resource", RESOURCE_ADDRESS, resource);
+        }
+        Ok(())
+    }
+});
@@ -0,0 +1,194 @@
+use crate::core::types::ComponentId;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, Exp, Normal, Poisson};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Deterministic random stream owned by one component
+///
+/// Every component gets its own ChaCha8 stream derived from the master seed in
+/// `SimulationConfig` and its stable component id, so draws never depend on
+/// thread scheduling or on the order other components run in.
+#[derive(Debug, Clone)]
+pub struct ComponentRng {
+    rng: ChaCha8Rng,
+}
+
+impl ComponentRng {
+    /// Create the stream for `component_id` under `master_seed`
+    pub fn for_component(master_seed: u64, component_id: &ComponentId) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(master_seed);
+        rng.set_stream(stream_id(component_id.id()));
+        Self { rng }
+    }
+
+    /// Restore a stream from a saved state
+    pub fn from_state(state: &RngState) -> Self {
+        let mut rng = ChaCha8Rng::from_seed(state.seed);
+        rng.set_stream(state.stream);
+        rng.set_word_pos(state.word_pos);
+        Self { rng }
+    }
+
+    /// Capture the stream position for checkpointing
+    pub fn state(&self) -> RngState {
+        RngState {
+            seed: self.rng.get_seed(),
+            stream: self.rng.get_stream(),
+            word_pos: self.rng.get_word_pos(),
+        }
+    }
+
+    /// Position in the random stream, in 32-bit words
+    pub(crate) fn word_pos(&self) -> u128 {
+        self.rng.get_word_pos()
+    }
+
+    /// Move to a position in the random stream
+    pub(crate) fn seek(&mut self, word_pos: u128) {
+        self.rng.set_word_pos(word_pos);
+    }
+
+    /// Uniform float in `[low, high)`
+    pub fn uniform(&mut self, low: f64, high: f64) -> Result<f64, String> {
+        if low.is_nan() || high.is_nan() || low >= high {
+            return Err(format!("Invalid uniform range [{}, {})", low, high));
+        }
+        Ok(self.rng.gen_range(low..high))
+    }
+
+    /// Uniform integer in `[low, high]`
+    pub fn uniform_int(&mut self, low: i64, high: i64) -> Result<i64, String> {
+        if low > high {
+            return Err(format!("Invalid uniform range [{}, {}]", low, high));
+        }
+        Ok(self.rng.gen_range(low..=high))
+    }
+
+    /// `true` with probability `p`
+    pub fn chance(&mut self, p: f64) -> Result<bool, String> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(format!("Invalid probability {}", p));
+        }
+        Ok(self.rng.gen_bool(p))
+    }
+
+    /// Exponential sample with the given rate
+    pub fn exponential(&mut self, rate: f64) -> Result<f64, String> {
+        let dist = Exp::new(rate).map_err(|e| format!("Invalid exponential rate {}: {}", rate, e))?;
+        Ok(dist.sample(&mut self.rng))
+    }
+
+    /// Normal sample with the given mean and standard deviation
+    pub fn normal(&mut self, mean: f64, std_dev: f64) -> Result<f64, String> {
+        let dist = Normal::new(mean, std_dev)
+            .map_err(|e| format!("Invalid normal parameters ({}, {}): {}", mean, std_dev, e))?;
+        Ok(dist.sample(&mut self.rng))
+    }
+
+    /// Poisson sample with the given mean
+    pub fn poisson(&mut self, lambda: f64) -> Result<u64, String> {
+        let dist = Poisson::new(lambda).map_err(|e| format!("Invalid Poisson mean {}: {}", lambda, e))?;
+        Ok(dist.sample(&mut self.rng) as u64)
+    }
+
+    /// Sample any distribution, such as those in `rsim::core::random::distributions`
+    pub fn sample<T, D: Distribution<T>>(&mut self, dist: &D) -> T {
+        dist.sample(&mut self.rng)
+    }
+}
+
+impl RngCore for ComponentRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// Stable stream id for a component (FNV-1a of its id)
+fn stream_id(id: &str) -> u64 {
+    id.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Saved position of one component's random stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RngState {
+    pub seed: [u8; 32],
+    pub stream: u64,
+    pub word_pos: u128,
+}
+
+/// Saved random streams of all components, keyed by component id
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RngCheckpoint {
+    pub master_seed: u64,
+    pub states: BTreeMap<String, RngState>,
+}
+
+impl RngCheckpoint {
+    /// Serialize the checkpoint as JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("Failed to serialize RNG checkpoint: {}", e))
+    }
+
+    /// Parse a checkpoint from JSON
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse RNG checkpoint: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(name: &str) -> ComponentId {
+        ComponentId::new(name.to_string(), "Test".to_string())
+    }
+
+    #[test]
+    fn test_streams_are_per_component_and_reproducible() {
+        let mut a1 = ComponentRng::for_component(7, &id("Fryer0"));
+        let mut a2 = ComponentRng::for_component(7, &id("Fryer0"));
+        let mut b = ComponentRng::for_component(7, &id("Fryer1"));
+        let mut c = ComponentRng::for_component(8, &id("Fryer0"));
+        let draws: Vec<u64> = (0..4).map(|_| a1.next_u64()).collect();
+        assert_eq!(draws, (0..4).map(|_| a2.next_u64()).collect::<Vec<_>>());
+        assert_ne!(draws, (0..4).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(draws, (0..4).map(|_| c.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        let mut rng = ComponentRng::for_component(1, &id("Baker0"));
+        rng.normal(0.0, 1.0).unwrap();
+        let mut restored = ComponentRng::from_state(&rng.state());
+        assert_eq!(rng.next_u64(), restored.next_u64());
+        assert!(rng.exponential(-1.0).is_err());
+        assert!(rng.uniform(2.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_sample_reexported_distribution() {
+        use crate::core::random::distributions::Binomial;
+        let dist = Binomial::new(10, 0.5).unwrap();
+        let mut a = ComponentRng::for_component(3, &id("Baker0"));
+        let mut b = ComponentRng::for_component(3, &id("Baker0"));
+        let draws: Vec<u64> = (0..5).map(|_| a.sample(&dist)).collect();
+        assert!(draws.iter().all(|draw| *draw <= 10));
+        assert_eq!(draws, (0..5).map(|_| b.sample(&dist)).collect::<Vec<_>>());
+    }
+}
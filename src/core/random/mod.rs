@@ -0,0 +1,7 @@
+pub mod component_rng;
+
+// Re-export commonly used types
+pub use component_rng::{ComponentRng, RngState, RngCheckpoint};
+
+/// Distributions for use with `ComponentRng::sample` (re-exported from `rand_distr`)
+pub use rand_distr as distributions;
@@ -0,0 +1,74 @@
+use crate::core::values::typed_value::TypedValue;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::any::Any;
+use std::collections::HashMap;
+
+type EncodeFn = fn(&dyn Any) -> Result<Value, String>;
+type DecodeFn = fn(Value) -> Result<TypedValue, String>;
+
+/// JSON encoding for type-erased values written to an input log
+///
+/// Values are keyed by `std::any::type_name`, so a log can be decoded by a
+/// later build of the same model. Common primitive types are registered by default.
+#[derive(Debug, Clone)]
+pub struct ValueCodec {
+    codecs: HashMap<&'static str, (EncodeFn, DecodeFn)>,
+}
+
+impl ValueCodec {
+    /// Create a codec that knows the common primitive types
+    pub fn new() -> Self {
+        let mut codec = Self { codecs: HashMap::new() };
+        codec.register::<i32>();
+        codec.register::<i64>();
+        codec.register::<u32>();
+        codec.register::<u64>();
+        codec.register::<usize>();
+        codec.register::<f32>();
+        codec.register::<f64>();
+        codec.register::<bool>();
+        codec.register::<String>();
+        codec
+    }
+
+    /// Register a serializable type
+    pub fn register<T>(&mut self) -> &mut Self
+    where
+        T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        let encode: EncodeFn = |value| {
+            let typed = value.downcast_ref::<T>()
+                .ok_or_else(|| format!("Codec type mismatch for {}", std::any::type_name::<T>()))?;
+            serde_json::to_value(typed).map_err(|e| e.to_string())
+        };
+        let decode: DecodeFn = |value| {
+            serde_json::from_value::<T>(value)
+                .map(TypedValue::new)
+                .map_err(|e| format!("Failed to decode {}: {}", std::any::type_name::<T>(), e))
+        };
+        self.codecs.insert(std::any::type_name::<T>(), (encode, decode));
+        self
+    }
+
+    /// Encode a value
+    pub fn encode(&self, value: &TypedValue) -> Result<Value, String> {
+        let (encode, _) = self.codecs.get(value.type_name())
+            .ok_or_else(|| format!("No codec registered for type '{}'", value.type_name()))?;
+        encode(value.as_any())
+    }
+
+    /// Decode a value previously encoded for `type_name`
+    pub fn decode(&self, type_name: &str, value: Value) -> Result<TypedValue, String> {
+        let (_, decode) = self.codecs.get(type_name)
+            .ok_or_else(|| format!("No codec registered for type '{}'", type_name))?;
+        decode(value)
+    }
+}
+
+impl Default for ValueCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,60 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Gateway for values a component takes from outside the model (wall clock, environment, ...)
+///
+/// Wrap every such read in `capture` so it is logged while recording and fed
+/// back from the log while replaying:
+///
+/// ```ignore
+/// let now = ctx.external.capture("now_ms", || wall_clock_ms())?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ExternalInputs {
+    recording: bool,
+    replay: Option<Vec<(String, Value)>>,
+    recorded: Vec<(String, Value)>,
+}
+
+impl ExternalInputs {
+    /// Pass-through inputs (no recording, no replay)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inputs that return `replay` values instead of calling the live source, and log captures when `recording`
+    pub(crate) fn with_log(replay: Option<Vec<(String, Value)>>, recording: bool) -> Self {
+        Self { recording, replay, recorded: Vec::new() }
+    }
+
+    /// Get an external value, from the log when replaying or from `live` otherwise
+    ///
+    /// During replay, the first logged value with the same name is used, so
+    /// reordering captures within a cycle is harmless. Names missing from the
+    /// log fall back to `live`.
+    pub fn capture<T, F>(&mut self, name: &str, live: F) -> Result<T, String>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> T,
+    {
+        let replayed = self.replay.as_mut()
+            .and_then(|replay| replay.iter().position(|(logged, _)| logged == name).map(|index| replay.remove(index)));
+        let value = match replayed {
+            Some((_, value)) => serde_json::from_value(value)
+                .map_err(|e| format!("Failed to replay external input '{}': {}", name, e))?,
+            None => live(),
+        };
+        if self.recording {
+            let encoded = serde_json::to_value(&value)
+                .map_err(|e| format!("Failed to record external input '{}': {}", name, e))?;
+            self.recorded.push((name.to_string(), encoded));
+        }
+        Ok(value)
+    }
+
+    /// Take the values captured while recording
+    pub(crate) fn take_recorded(&mut self) -> Vec<(String, Value)> {
+        std::mem::take(&mut self.recorded)
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// One stimulus value injected into an input port
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StimulusRecord {
+    pub component: String,
+    pub port: String,
+    pub type_name: String,
+    pub value: Value,
+}
+
+/// Everything consumed from outside the model during one cycle
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CycleRecord {
+    pub cycle: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stimuli: Vec<StimulusRecord>,
+    /// Random stream position at the start of the cycle, for components that drew numbers
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rng: BTreeMap<String, u128>,
+    /// Values captured through `ExternalInputs`, per component
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub external: BTreeMap<String, Vec<(String, Value)>>,
+}
+
+impl CycleRecord {
+    /// Check if nothing was consumed in this cycle
+    pub fn is_empty(&self) -> bool {
+        self.stimuli.is_empty() && self.rng.is_empty() && self.external.is_empty()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct LogHeader {
+    version: u32,
+    master_seed: u64,
+}
+
+/// Log of all nondeterministic inputs of a run, for exact replay
+///
+/// Stored as JSON lines: a header with the master seed, then one line per
+/// cycle that consumed anything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputLog {
+    pub master_seed: u64,
+    pub cycles: BTreeMap<u64, CycleRecord>,
+}
+
+impl InputLog {
+    const VERSION: u32 = 1;
+
+    /// Create an empty log for a run with `master_seed`
+    pub fn new(master_seed: u64) -> Self {
+        Self { master_seed, cycles: BTreeMap::new() }
+    }
+
+    /// Get the record of one cycle
+    pub fn cycle(&self, cycle: u64) -> Option<&CycleRecord> {
+        self.cycles.get(&cycle)
+    }
+
+    /// Last cycle with a record
+    pub fn last_cycle(&self) -> Option<u64> {
+        self.cycles.keys().next_back().copied()
+    }
+
+    /// Write the log as JSON lines
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        let file = std::fs::File::create(path)
+            .map_err(|e| format!("Failed to create '{}': {}", path.display(), e))?;
+        let mut writer = BufWriter::new(file);
+        let header = LogHeader { version: Self::VERSION, master_seed: self.master_seed };
+        let mut write_line = |line: Result<String, serde_json::Error>| -> Result<(), String> {
+            let line = line.map_err(|e| format!("Failed to serialize input log: {}", e))?;
+            writeln!(writer, "{}", line).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+        };
+        write_line(serde_json::to_string(&header))?;
+        for record in self.cycles.values() {
+            write_line(serde_json::to_string(record))?;
+        }
+        writer.flush().map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+    }
+
+    /// Read a log written by `save`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)
+            .map_err(|e| format!("Failed to open '{}': {}", path.display(), e))?;
+        let mut lines = BufReader::new(file).lines();
+        let header_line = lines.next()
+            .ok_or_else(|| format!("Input log '{}' is empty", path.display()))?
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        let header: LogHeader = serde_json::from_str(&header_line)
+            .map_err(|e| format!("Invalid input log header: {}", e))?;
+        if header.version != Self::VERSION {
+            return Err(format!("Unsupported input log version {}", header.version));
+        }
+
+        let mut log = Self::new(header.master_seed);
+        for (index, line) in lines.enumerate() {
+            let line = line.map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: CycleRecord = serde_json::from_str(&line)
+                .map_err(|e| format!("Invalid input log line {}: {}", index + 2, e))?;
+            log.cycles.insert(record.cycle, record);
+        }
+        Ok(log)
+    }
+}
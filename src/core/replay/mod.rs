@@ -0,0 +1,8 @@
+pub mod codec;
+pub mod external;
+pub mod log;
+
+// Re-export commonly used types
+pub use codec::ValueCodec;
+pub use external::ExternalInputs;
+pub use log::{InputLog, CycleRecord, StimulusRecord};
@@ -0,0 +1,10 @@
+pub mod recorder;
+pub mod registry;
+pub mod summary;
+pub mod output_analysis;
+
+// Re-export commonly used types
+pub use recorder::StatsRecorder;
+pub use registry::{StatsRegistry, Histogram, TimeWeighted};
+pub use summary::{Summary, TimeWeightedSummary, StatsReport, t_critical_95};
+pub use output_analysis::{BatchMeans, MserResult, batch_means, mser};
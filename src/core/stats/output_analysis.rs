@@ -0,0 +1,118 @@
+use crate::core::stats::summary::Summary;
+
+/// Result of a batch-means analysis of one long run
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchMeans {
+    /// Number of samples per batch
+    pub batch_size: usize,
+    /// Mean of each batch, in order
+    pub means: Vec<f64>,
+    /// Summary of the batch means; its confidence interval is the steady-state estimate
+    pub summary: Summary,
+    /// Lag-1 autocorrelation of the batch means (close to 0 when batches are large enough)
+    pub lag1_autocorrelation: f64,
+}
+
+/// Split samples into `batches` equal batches and summarize their means
+///
+/// Leftover samples are dropped from the start of the series, where
+/// initialization bias is largest. Requires at least two batches.
+pub fn batch_means(samples: &[f64], batches: usize) -> Option<BatchMeans> {
+    if batches < 2 || samples.len() < batches {
+        return None;
+    }
+    let batch_size = samples.len() / batches;
+    let start = samples.len() - batch_size * batches;
+    let means: Vec<f64> = samples[start..]
+        .chunks(batch_size)
+        .map(|batch| batch.iter().sum::<f64>() / batch_size as f64)
+        .collect();
+    let summary = Summary::from_samples(&means)?;
+    let lag1_autocorrelation = lag1_autocorrelation(&means, summary.mean);
+    Some(BatchMeans { batch_size, means, summary, lag1_autocorrelation })
+}
+
+fn lag1_autocorrelation(values: &[f64], mean: f64) -> f64 {
+    let variance: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+    if variance == 0.0 {
+        return 0.0;
+    }
+    let covariance: f64 = values.windows(2).map(|w| (w[0] - mean) * (w[1] - mean)).sum();
+    covariance / variance
+}
+
+/// Warm-up point chosen by the MSER rule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MserResult {
+    /// Number of leading samples to discard
+    pub truncation: usize,
+    /// MSER statistic at the chosen truncation point
+    pub statistic: f64,
+}
+
+/// Detect the warm-up period with MSER-m (Marginal Standard Error Rule)
+///
+/// Samples are averaged in batches of `batch_size` (5 gives the usual
+/// MSER-5) and the truncation point minimizing the marginal standard error
+/// of the remaining batches is chosen, searching only the first half.
+pub fn mser(samples: &[f64], batch_size: usize) -> Option<MserResult> {
+    if batch_size == 0 {
+        return None;
+    }
+    let batches: Vec<f64> = samples
+        .chunks_exact(batch_size)
+        .map(|batch| batch.iter().sum::<f64>() / batch_size as f64)
+        .collect();
+    let k = batches.len();
+    if k < 2 {
+        return None;
+    }
+
+    // Suffix sums of values and squares give each candidate in O(1)
+    let mut sum = 0.0;
+    let mut sum_sq = 0.0;
+    let mut suffix = vec![(0.0, 0.0); k + 1];
+    for d in (0..k).rev() {
+        sum += batches[d];
+        sum_sq += batches[d] * batches[d];
+        suffix[d] = (sum, sum_sq);
+    }
+
+    let mut best: Option<MserResult> = None;
+    for (d, &(sum, sum_sq)) in suffix.iter().enumerate().take(k / 2 + 1) {
+        let n = (k - d) as f64;
+        let deviations = (sum_sq - sum * sum / n).max(0.0);
+        let statistic = deviations / (n * n);
+        if best.is_none_or(|b| statistic < b.statistic) {
+            best = Some(MserResult { truncation: d * batch_size, statistic });
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_means() {
+        let samples: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let result = batch_means(&samples, 3).unwrap();
+        // One leading sample dropped, then batches [1,2,3] [4,5,6] [7,8,9]
+        assert_eq!(result.batch_size, 3);
+        assert_eq!(result.means, vec![2.0, 5.0, 8.0]);
+        assert_eq!(result.summary.mean, 5.0);
+        assert!(batch_means(&samples, 1).is_none());
+    }
+
+    #[test]
+    fn test_mser_detects_initial_transient() {
+        // Ramp up from an empty system for 50 samples, then steady around 10
+        let samples: Vec<f64> = (0..500)
+            .map(|i| if i < 50 { i as f64 / 5.0 } else { 10.0 + if i % 2 == 0 { 0.5 } else { -0.5 } })
+            .collect();
+        let result = mser(&samples, 5).unwrap();
+        assert!((45..=55).contains(&result.truncation), "truncation {}", result.truncation);
+        assert!(mser(&samples[..5], 5).is_none());
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::BTreeMap;
+
+/// Per-evaluation statistics buffer handed to components through `EvaluationContext`
+///
+/// Updates are collected locally and merged into the engine's `StatsRegistry`
+/// after the component finishes, so recording is safe in parallel execution.
+/// Statistic names are global: components recording under the same name
+/// contribute to the same statistic.
+#[derive(Debug, Clone, Default)]
+pub struct StatsRecorder {
+    pub(crate) counters: BTreeMap<String, u64>,
+    pub(crate) samples: Vec<(String, f64)>,
+    pub(crate) levels: Vec<(String, f64)>,
+}
+
+impl StatsRecorder {
+    /// Create an empty recorder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increment a counter by one
+    pub fn increment(&mut self, name: &str) {
+        self.add(name, 1);
+    }
+
+    /// Increment a counter by `amount`
+    pub fn add(&mut self, name: &str, amount: u64) {
+        *self.counters.entry(name.to_string()).or_insert(0) += amount;
+    }
+
+    /// Record a sample into a histogram (e.g. a wait time)
+    pub fn record(&mut self, name: &str, value: f64) {
+        self.samples.push((name.to_string(), value));
+    }
+
+    /// Set the current level of a time-weighted statistic (e.g. a queue length)
+    ///
+    /// The level holds from the current cycle until it is set again.
+    pub fn set_level(&mut self, name: &str, value: f64) {
+        self.levels.push((name.to_string(), value));
+    }
+
+    /// Check if nothing was recorded
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty() && self.samples.is_empty() && self.levels.is_empty()
+    }
+}
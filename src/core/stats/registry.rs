@@ -0,0 +1,261 @@
+use crate::core::stats::recorder::StatsRecorder;
+use crate::core::stats::summary::{Summary, TimeWeightedSummary, StatsReport};
+use std::collections::BTreeMap;
+
+/// Histogram statistic that keeps every sample for exact percentiles
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Histogram {
+    samples: Vec<f64>,
+}
+
+impl Histogram {
+    /// Create an empty histogram
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a sample
+    pub fn record(&mut self, value: f64) {
+        self.samples.push(value);
+    }
+
+    /// Get all samples in recording order
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    /// Get the number of samples
+    pub fn count(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Summarize the samples, or `None` if there are none
+    pub fn summary(&self) -> Option<Summary> {
+        Summary::from_samples(&self.samples)
+    }
+
+    /// Count samples in `bins` equal-width buckets spanning min..=max
+    ///
+    /// Returns `(lower, upper, count)` per bucket.
+    pub fn bin_counts(&self, bins: usize) -> Vec<(f64, f64, usize)> {
+        if self.samples.is_empty() || bins == 0 {
+            return Vec::new();
+        }
+        let min = self.samples.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = self.samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let width = if max > min { (max - min) / bins as f64 } else { 1.0 };
+
+        let mut counts = vec![0usize; bins];
+        for &value in &self.samples {
+            let index = (((value - min) / width) as usize).min(bins - 1);
+            counts[index] += 1;
+        }
+        counts.into_iter()
+            .enumerate()
+            .map(|(i, count)| (min + width * i as f64, min + width * (i + 1) as f64, count))
+            .collect()
+    }
+}
+
+/// Time-weighted statistic: a level that holds until changed (e.g. queue length)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeWeighted {
+    start_cycle: u64,
+    last_cycle: u64,
+    current: f64,
+    weighted_sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TimeWeighted {
+    /// Start tracking with an initial level at `cycle`
+    pub fn new(cycle: u64, value: f64) -> Self {
+        Self {
+            start_cycle: cycle,
+            last_cycle: cycle,
+            current: value,
+            weighted_sum: 0.0,
+            min: value,
+            max: value,
+        }
+    }
+
+    /// Change the level at `cycle`
+    pub fn update(&mut self, cycle: u64, value: f64) {
+        let cycle = cycle.max(self.last_cycle);
+        self.weighted_sum += self.current * (cycle - self.last_cycle) as f64;
+        self.last_cycle = cycle;
+        self.current = value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Get the current level
+    pub fn current(&self) -> f64 {
+        self.current
+    }
+
+    /// Time-weighted mean over `[start, end_cycle)`
+    pub fn mean_until(&self, end_cycle: u64) -> f64 {
+        let end = end_cycle.max(self.last_cycle);
+        let duration = end - self.start_cycle;
+        if duration == 0 {
+            return self.current;
+        }
+        (self.weighted_sum + self.current * (end - self.last_cycle) as f64) / duration as f64
+    }
+
+    /// Summarize the statistic over `[start, end_cycle)`
+    pub fn summary(&self, end_cycle: u64) -> TimeWeightedSummary {
+        TimeWeightedSummary {
+            mean: self.mean_until(end_cycle),
+            min: self.min,
+            max: self.max,
+            last: self.current,
+            duration: end_cycle.max(self.last_cycle) - self.start_cycle,
+        }
+    }
+}
+
+/// Registry of all counters, histograms and time-weighted statistics in a run
+#[derive(Debug, Clone, Default)]
+pub struct StatsRegistry {
+    counters: BTreeMap<String, u64>,
+    histograms: BTreeMap<String, Histogram>,
+    time_weighted: BTreeMap<String, TimeWeighted>,
+}
+
+impl StatsRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merge a component's recorded updates for `cycle`
+    pub fn apply(&mut self, recorder: StatsRecorder, cycle: u64) {
+        for (name, amount) in recorder.counters {
+            *self.counters.entry(name).or_insert(0) += amount;
+        }
+        for (name, value) in recorder.samples {
+            self.histograms.entry(name).or_default().record(value);
+        }
+        for (name, value) in recorder.levels {
+            match self.time_weighted.get_mut(&name) {
+                Some(stat) => stat.update(cycle, value),
+                None => {
+                    self.time_weighted.insert(name, TimeWeighted::new(cycle, value));
+                }
+            }
+        }
+    }
+
+    /// Get a counter value (0 if never incremented)
+    pub fn counter(&self, name: &str) -> u64 {
+        self.counters.get(name).copied().unwrap_or(0)
+    }
+
+    /// Get a histogram by name
+    pub fn histogram(&self, name: &str) -> Option<&Histogram> {
+        self.histograms.get(name)
+    }
+
+    /// Get a time-weighted statistic by name
+    pub fn time_weighted(&self, name: &str) -> Option<&TimeWeighted> {
+        self.time_weighted.get(name)
+    }
+
+    /// Check if nothing was recorded
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty() && self.histograms.is_empty() && self.time_weighted.is_empty()
+    }
+
+    /// Discard everything recorded so far, e.g. at the end of a warm-up period
+    ///
+    /// Time-weighted statistics keep their current level and restart at `cycle`.
+    pub fn reset(&mut self, cycle: u64) {
+        self.counters.clear();
+        self.histograms.clear();
+        for stat in self.time_weighted.values_mut() {
+            *stat = TimeWeighted::new(cycle, stat.current);
+        }
+    }
+
+    /// Remove all recorded statistics
+    pub fn clear(&mut self) {
+        self.counters.clear();
+        self.histograms.clear();
+        self.time_weighted.clear();
+    }
+
+    /// Summarize all statistics, closing time-weighted averages at `end_cycle`
+    pub fn report(&self, end_cycle: u64) -> StatsReport {
+        StatsReport {
+            counters: self.counters.clone(),
+            histograms: self.histograms.iter()
+                .filter_map(|(name, h)| h.summary().map(|s| (name.clone(), s)))
+                .collect(),
+            time_weighted: self.time_weighted.iter()
+                .map(|(name, t)| (name.clone(), t.summary(end_cycle)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_merges_recorders() {
+        let mut registry = StatsRegistry::new();
+        let mut a = StatsRecorder::new();
+        a.increment("served");
+        a.record("wait", 2.0);
+        let mut b = StatsRecorder::new();
+        b.add("served", 2);
+        b.record("wait", 4.0);
+        registry.apply(a, 1);
+        registry.apply(b, 1);
+
+        assert_eq!(registry.counter("served"), 3);
+        assert_eq!(registry.counter("missing"), 0);
+        assert_eq!(registry.histogram("wait").unwrap().samples(), &[2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_time_weighted_mean() {
+        // Level 2 for cycles 1-2, level 6 for cycle 3, report at end of cycle 4
+        let mut stat = TimeWeighted::new(1, 2.0);
+        stat.update(3, 6.0);
+        stat.update(4, 0.0);
+        assert_eq!(stat.mean_until(5), (2.0 * 2.0 + 6.0) / 4.0);
+        let summary = stat.summary(5);
+        assert_eq!((summary.min, summary.max, summary.last, summary.duration), (0.0, 6.0, 0.0, 4));
+    }
+
+    #[test]
+    fn test_reset_keeps_current_level() {
+        let mut registry = StatsRegistry::new();
+        let mut recorder = StatsRecorder::new();
+        recorder.increment("served");
+        recorder.set_level("queue", 4.0);
+        registry.apply(recorder, 1);
+        registry.reset(11);
+
+        assert_eq!(registry.counter("served"), 0);
+        let queue = registry.time_weighted("queue").unwrap();
+        assert_eq!((queue.current(), queue.mean_until(21)), (4.0, 4.0));
+        assert_eq!(queue.summary(21).duration, 10);
+    }
+
+    #[test]
+    fn test_histogram_bins() {
+        let mut histogram = Histogram::new();
+        for value in [0.0, 1.0, 2.0, 3.0, 4.0] {
+            histogram.record(value);
+        }
+        let counts: Vec<usize> = histogram.bin_counts(2).iter().map(|b| b.2).collect();
+        assert_eq!(counts, vec![2, 3]);
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Two-sided 95% Student t critical values for 1..=30 degrees of freedom
+const T_TABLE_95: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228,
+    2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086,
+    2.080, 2.074, 2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+];
+
+/// Two-sided 95% Student t critical value, falling back to the normal 1.96 above 30 d.o.f.
+pub fn t_critical_95(degrees_of_freedom: usize) -> f64 {
+    match degrees_of_freedom {
+        0 => f64::NAN,
+        df if df <= T_TABLE_95.len() => T_TABLE_95[df - 1],
+        _ => 1.96,
+    }
+}
+
+/// Summary of a set of samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub mean: f64,
+    /// Sample standard deviation (0 for a single sample)
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p95: f64,
+    pub p99: f64,
+    /// Half-width of the 95% confidence interval of the mean
+    pub ci95_half_width: f64,
+}
+
+impl Summary {
+    /// Summarize samples, or `None` if there are none
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let count = samples.len();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let std_dev = if count > 1 {
+            let variance = samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (count - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+        let ci95_half_width = if count > 1 {
+            t_critical_95(count - 1) * std_dev / (count as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Some(Self {
+            count,
+            mean,
+            std_dev,
+            min: sorted[0],
+            max: sorted[count - 1],
+            p50: percentile(&sorted, 50.0),
+            p90: percentile(&sorted, 90.0),
+            p95: percentile(&sorted, 95.0),
+            p99: percentile(&sorted, 99.0),
+            ci95_half_width,
+        })
+    }
+
+    /// 95% confidence interval of the mean as `(low, high)`
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        (self.mean - self.ci95_half_width, self.mean + self.ci95_half_width)
+    }
+}
+
+/// Linearly interpolated percentile of sorted samples (`p` in 0..=100)
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p / 100.0) * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Summary of a time-weighted statistic
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeWeightedSummary {
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    pub last: f64,
+    /// Number of cycles covered
+    pub duration: u64,
+}
+
+/// End-of-run summary of all statistics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatsReport {
+    pub counters: BTreeMap<String, u64>,
+    pub histograms: BTreeMap<String, Summary>,
+    pub time_weighted: BTreeMap<String, TimeWeightedSummary>,
+}
+
+impl fmt::Display for StatsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.counters.is_empty() {
+            writeln!(f, "Counters:")?;
+            for (name, value) in &self.counters {
+                writeln!(f, "  {}: {}", name, value)?;
+            }
+        }
+        if !self.histograms.is_empty() {
+            writeln!(f, "Histograms:")?;
+            for (name, s) in &self.histograms {
+                writeln!(
+                    f,
+                    "  {}: n={} mean={:.4} ±{:.4} (95% CI) sd={:.4} min={:.4} p50={:.4} p90={:.4} p95={:.4} p99={:.4} max={:.4}",
+                    name, s.count, s.mean, s.ci95_half_width, s.std_dev, s.min, s.p50, s.p90, s.p95, s.p99, s.max
+                )?;
+            }
+        }
+        if !self.time_weighted.is_empty() {
+            writeln!(f, "Time-weighted:")?;
+            for (name, s) in &self.time_weighted {
+                writeln!(
+                    f,
+                    "  {}: mean={:.4} min={:.4} max={:.4} last={:.4} over {} cycles",
+                    name, s.mean, s.min, s.max, s.last, s.duration
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_statistics() {
+        let summary = Summary::from_samples(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert_eq!(summary.count, 5);
+        assert_eq!(summary.mean, 3.0);
+        assert_eq!(summary.p50, 3.0);
+        assert!((summary.p90 - 4.6).abs() < 1e-12);
+        assert!((summary.std_dev - 2.5f64.sqrt()).abs() < 1e-12);
+        let (low, high) = summary.confidence_interval();
+        assert!((high - low - 2.0 * 2.776 * summary.std_dev / 5f64.sqrt()).abs() < 1e-12);
+        assert!(Summary::from_samples(&[]).is_none());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod source;
+pub mod trace;
+
+// Re-export commonly used types
+pub use source::{StimulusSource, ClosureStimulus, IteratorStimulus};
+pub use trace::TraceStimulus;
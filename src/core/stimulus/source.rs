@@ -0,0 +1,130 @@
+use crate::core::values::typed_value::TypedValue;
+
+/// External source of values driven into a component input port
+///
+/// A stimulus source is polled once per cycle, before any component is
+/// evaluated. A returned value is delivered to the target input port as an
+/// `Event` stamped with the current cycle, exactly as if an upstream
+/// component had emitted it.
+pub trait StimulusSource: Send + Sync {
+    /// Produce the value for the given cycle, if any
+    fn next_value(&mut self, cycle: u64) -> Result<Option<TypedValue>, String>;
+
+    /// Check if the source can start over; `CycleEngine::reset` asks every source before rewinding any
+    fn can_rewind(&self) -> bool {
+        false
+    }
+
+    /// Start over from cycle 1; called when the engine is reset
+    fn rewind(&mut self) -> Result<(), String> {
+        Err("Stimulus source does not support rewinding".to_string())
+    }
+}
+
+/// Stimulus source backed by a closure called once per cycle
+///
+/// Only closures created with `stateless` can be rewound; an `FnMut` closure
+/// may carry state that a reset cannot restore.
+pub struct ClosureStimulus<T, F>
+where
+    F: FnMut(u64) -> Option<T>,
+{
+    generator: F,
+    rewindable: bool,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, F> ClosureStimulus<T, F>
+where
+    T: Send + Sync + Clone + 'static,
+    F: FnMut(u64) -> Option<T> + Send + Sync,
+{
+    /// Create a stimulus from a closure mapping cycle number to an optional value
+    pub fn new(generator: F) -> Self {
+        Self {
+            generator,
+            rewindable: false,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, F> ClosureStimulus<T, F>
+where
+    T: Send + Sync + Clone + 'static,
+    F: Fn(u64) -> Option<T> + Send + Sync,
+{
+    /// Create a stimulus from a pure function of the cycle number, which can be rewound
+    pub fn stateless(generator: F) -> Self {
+        Self {
+            generator,
+            rewindable: true,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, F> StimulusSource for ClosureStimulus<T, F>
+where
+    T: Send + Sync + Clone + 'static,
+    F: FnMut(u64) -> Option<T> + Send + Sync,
+{
+    fn next_value(&mut self, cycle: u64) -> Result<Option<TypedValue>, String> {
+        Ok((self.generator)(cycle).map(TypedValue::new))
+    }
+
+    fn can_rewind(&self) -> bool {
+        self.rewindable
+    }
+
+    fn rewind(&mut self) -> Result<(), String> {
+        if self.rewindable {
+            Ok(())
+        } else {
+            Err("Closure stimulus may carry state; create it with ClosureStimulus::stateless to rewind".to_string())
+        }
+    }
+}
+
+/// Stimulus source that delivers one item per cycle from an iterator
+///
+/// Once the iterator is exhausted no further events are delivered. A copy
+/// of the fresh iterator is kept so the stimulus can be rewound.
+pub struct IteratorStimulus<I: Iterator> {
+    start: I,
+    items: I,
+}
+
+impl<I> IteratorStimulus<I>
+where
+    I: Iterator + Clone + Send + Sync,
+    I::Item: Send + Sync + Clone + 'static,
+{
+    /// Create a stimulus that yields the next iterator item every cycle
+    pub fn new<C: IntoIterator<IntoIter = I>>(items: C) -> Self {
+        let items = items.into_iter();
+        Self {
+            start: items.clone(),
+            items,
+        }
+    }
+}
+
+impl<I> StimulusSource for IteratorStimulus<I>
+where
+    I: Iterator + Clone + Send + Sync,
+    I::Item: Send + Sync + Clone + 'static,
+{
+    fn next_value(&mut self, _cycle: u64) -> Result<Option<TypedValue>, String> {
+        Ok(self.items.next().map(TypedValue::new))
+    }
+
+    fn can_rewind(&self) -> bool {
+        true
+    }
+
+    fn rewind(&mut self) -> Result<(), String> {
+        self.items = self.start.clone();
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::core::stimulus::source::StimulusSource;
+use crate::core::values::typed_value::TypedValue;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Stimulus source that replays a recorded trace keyed by cycle
+///
+/// Each cycle with a recorded value delivers exactly one event; cycles
+/// without an entry deliver nothing.
+#[derive(Debug, Clone)]
+pub struct TraceStimulus<T> {
+    entries: BTreeMap<u64, T>,
+}
+
+/// Single line of a JSON-lines trace file
+#[derive(serde::Deserialize)]
+struct TraceLine<T> {
+    cycle: u64,
+    value: T,
+}
+
+impl<T: Send + Sync + Clone + 'static> TraceStimulus<T> {
+    /// Create a trace from (cycle, value) pairs
+    ///
+    /// Returns an error if two entries share the same cycle.
+    pub fn from_entries<I: IntoIterator<Item = (u64, T)>>(entries: I) -> Result<Self, String> {
+        let mut map = BTreeMap::new();
+        for (cycle, value) in entries {
+            if map.insert(cycle, value).is_some() {
+                return Err(format!("Duplicate trace entry for cycle {}", cycle));
+            }
+        }
+        Ok(Self { entries: map })
+    }
+
+    /// Load a CSV trace with `cycle,value` rows using a custom value parser
+    ///
+    /// Blank lines and lines starting with `#` are skipped, as is a first
+    /// line whose cycle column is not a number (a header row). Everything
+    /// after the first comma is passed to the parser.
+    pub fn from_csv_with<P, F>(path: P, parse: F) -> Result<Self, String>
+    where
+        P: AsRef<Path>,
+        F: Fn(&str) -> Result<T, String>,
+    {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)
+            .map_err(|e| format!("Failed to open trace '{}': {}", path.display(), e))?;
+        
+        let mut entries = Vec::new();
+        for (line_idx, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(|e| format!("Failed to read trace '{}': {}", path.display(), e))?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            let (cycle_field, value_field) = line.split_once(',')
+                .ok_or_else(|| format!("{}:{}: expected 'cycle,value'", path.display(), line_idx + 1))?;
+            let cycle = match cycle_field.trim().parse::<u64>() {
+                Ok(cycle) => cycle,
+                Err(_) if entries.is_empty() => continue, // header row
+                Err(e) => return Err(format!("{}:{}: invalid cycle '{}': {}",
+                                             path.display(), line_idx + 1, cycle_field, e)),
+            };
+            let value = parse(value_field.trim())
+                .map_err(|e| format!("{}:{}: {}", path.display(), line_idx + 1, e))?;
+            entries.push((cycle, value));
+        }
+        
+        Self::from_entries(entries)
+    }
+
+    /// Load a CSV trace with `cycle,value` rows, parsing values with `FromStr`
+    pub fn from_csv<P: AsRef<Path>>(path: P) -> Result<Self, String>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        Self::from_csv_with(path, |field| field.parse::<T>().map_err(|e| format!("invalid value '{}': {}", field, e)))
+    }
+
+    /// Load a JSON-lines trace where each line is `{"cycle": <u64>, "value": <T>}`
+    pub fn from_json_lines<P: AsRef<Path>>(path: P) -> Result<Self, String>
+    where
+        T: DeserializeOwned,
+    {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)
+            .map_err(|e| format!("Failed to open trace '{}': {}", path.display(), e))?;
+        
+        let mut entries = Vec::new();
+        for (line_idx, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(|e| format!("Failed to read trace '{}': {}", path.display(), e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let parsed: TraceLine<T> = serde_json::from_str(&line)
+                .map_err(|e| format!("{}:{}: {}", path.display(), line_idx + 1, e))?;
+            entries.push((parsed.cycle, parsed.value));
+        }
+        
+        Self::from_entries(entries)
+    }
+
+    /// Get the number of recorded entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the trace has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T: Send + Sync + Clone + 'static> StimulusSource for TraceStimulus<T> {
+    fn next_value(&mut self, cycle: u64) -> Result<Option<TypedValue>, String> {
+        Ok(self.entries.get(&cycle).cloned().map(TypedValue::new))
+    }
+
+    fn can_rewind(&self) -> bool {
+        true
+    }
+
+    fn rewind(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("rsim_trace_{}_{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_csv_trace_with_header() {
+        let path = temp_file("arrivals.csv", "cycle,customers\n1,3\n# comment\n4,1\n");
+        let mut trace = TraceStimulus::<i64>::from_csv(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        
+        assert_eq!(trace.len(), 2);
+        assert_eq!(trace.next_value(1).unwrap().unwrap().get::<i64>().unwrap(), &3);
+        assert!(trace.next_value(2).unwrap().is_none());
+        assert_eq!(trace.next_value(4).unwrap().unwrap().get::<i64>().unwrap(), &1);
+    }
+
+    #[test]
+    fn test_json_lines_trace() {
+        let path = temp_file("arrivals.jsonl", "{\"cycle\": 2, \"value\": \"walk-in\"}\n\n{\"cycle\": 5, \"value\": \"drive-thru\"}\n");
+        let mut trace = TraceStimulus::<String>::from_json_lines(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        
+        assert_eq!(trace.len(), 2);
+        assert_eq!(trace.next_value(5).unwrap().unwrap().get::<String>().unwrap(), "drive-thru");
+    }
+
+    #[test]
+    fn test_duplicate_cycle_rejected() {
+        let result = TraceStimulus::from_entries(vec![(1, 1i64), (1, 2i64)]);
+        assert!(result.unwrap_err().contains("Duplicate"));
+    }
+}
@@ -31,6 +31,11 @@ impl EventOutputMap {
             timestamp,
         }
     }
+    
+    /// Timestamp given to events set on this map, the current cycle during evaluation
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
 }
 
 impl EventOutputs for EventOutputMap {
@@ -0,0 +1,106 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+type EqFn = fn(&dyn Any, &dyn Any) -> bool;
+type FormatFn = fn(&dyn Any) -> String;
+
+/// Registry of equality and `Debug` formatting for type-erased values
+///
+/// Memory contents and event payloads are stored as `dyn Any`, so tools that
+/// compare or print them (determinism checks, debugging) look up the concrete
+/// type here. Types that were never registered are reported as opaque.
+#[derive(Debug, Clone, Default)]
+pub struct ValueInspector {
+    eq: HashMap<TypeId, EqFn>,
+    format: HashMap<TypeId, FormatFn>,
+}
+
+impl ValueInspector {
+    /// Create an empty inspector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an inspector that already knows the common primitive types
+    pub fn with_primitives() -> Self {
+        let mut inspector = Self::new();
+        inspector.register::<i32>();
+        inspector.register::<i64>();
+        inspector.register::<u32>();
+        inspector.register::<u64>();
+        inspector.register::<usize>();
+        inspector.register::<f32>();
+        inspector.register::<f64>();
+        inspector.register::<bool>();
+        inspector.register::<String>();
+        inspector.register::<Vec<u8>>();
+        inspector
+    }
+
+    /// Register equality and formatting for `T`
+    pub fn register<T: PartialEq + Debug + 'static>(&mut self) -> &mut Self {
+        self.register_eq::<T>();
+        self.register_debug::<T>()
+    }
+
+    /// Register equality for `T`
+    pub fn register_eq<T: PartialEq + 'static>(&mut self) -> &mut Self {
+        self.eq.insert(TypeId::of::<T>(), |a, b| {
+            match (a.downcast_ref::<T>(), b.downcast_ref::<T>()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            }
+        });
+        self
+    }
+
+    /// Register `Debug` formatting for `T`
+    pub fn register_debug<T: Debug + 'static>(&mut self) -> &mut Self {
+        self.format.insert(TypeId::of::<T>(), |value| {
+            value.downcast_ref::<T>().map(|v| format!("{:?}", v)).unwrap_or_default()
+        });
+        self
+    }
+
+    /// Check if values of this type can be compared
+    pub fn can_compare(&self, value: &dyn Any) -> bool {
+        self.eq.contains_key(&value.type_id())
+    }
+
+    /// Compare two values; `None` if their type has no registered equality
+    ///
+    /// Values of different types are never equal.
+    pub fn equals(&self, a: &dyn Any, b: &dyn Any) -> Option<bool> {
+        if a.type_id() != b.type_id() {
+            return Some(false);
+        }
+        self.eq.get(&a.type_id()).map(|eq| eq(a, b))
+    }
+
+    /// Format a value with `Debug`; `None` if its type has no registered formatter
+    pub fn format(&self, value: &dyn Any) -> Option<String> {
+        self.format.get(&value.type_id()).map(|format| format(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Custom(u8);
+
+    #[test]
+    fn test_inspector_compares_and_formats_registered_types() {
+        let mut inspector = ValueInspector::with_primitives();
+        assert_eq!(inspector.equals(&1i32, &1i32), Some(true));
+        assert_eq!(inspector.equals(&1i32, &1i64), Some(false));
+        assert_eq!(inspector.equals(&Custom(1), &Custom(1)), None);
+        assert_eq!(inspector.format(&Custom(1)), None);
+
+        inspector.register::<Custom>();
+        assert_eq!(inspector.equals(&Custom(1), &Custom(2)), Some(false));
+        assert_eq!(inspector.format(&Custom(3)).as_deref(), Some("Custom(3)"));
+    }
+}
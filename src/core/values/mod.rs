@@ -3,10 +3,12 @@ pub mod typed_value;
 pub mod traits;
 pub mod implementations;
 pub mod unified;
+pub mod inspect;
 
 // Re-export all public types
 pub use events::Event;
 pub use typed_value::{TypedValue, TypedData};
 pub use traits::{TypedInputs, TypedOutputs, EventInputs, EventOutputs};
 pub use implementations::{TypedInputMap, TypedOutputMap, EventInputMap, EventOutputMap};
-pub use unified::{UnifiedInputMap, UnifiedOutputMap};
\ No newline at end of file
+pub use unified::{UnifiedInputMap, UnifiedOutputMap};
+pub use inspect::ValueInspector;
@@ -52,6 +52,11 @@ impl TypedValue {
             .map_err(|_| format!("Failed to downcast to {}", std::any::type_name::<T>()))
     }
     
+    /// Get a type-erased reference to the contained value
+    pub fn as_any(&self) -> &dyn Any {
+        self.data.as_ref()
+    }
+    
     /// Get the type name of the contained value
     pub fn type_name(&self) -> &'static str {
         self.type_name
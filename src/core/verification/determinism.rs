@@ -0,0 +1,272 @@
+use crate::core::execution::config::{ConcurrencyMode, SimulationConfig};
+use crate::core::execution::cycle_engine::CycleEngine;
+use crate::core::values::inspect::ValueInspector;
+use crate::core::values::events::Event;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Model factory: builds an engine for the given configuration
+type ConfigFactory = Box<dyn Fn(SimulationConfig) -> Result<CycleEngine, String> + Send + Sync>;
+
+/// Where two engines first disagreed
+#[derive(Debug, Clone, PartialEq)]
+pub enum DivergenceLocation {
+    /// An output event differs or exists in only one engine
+    Output { component_id: String, port: String },
+    /// A memory address differs or exists in only one engine
+    Memory { memory_id: String, address: String },
+    /// The set of components differs between the two builds
+    Model { component_id: String },
+    /// One engine failed to execute the cycle
+    Execution,
+}
+
+/// First difference found between Sequential and Rayon execution
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    pub cycle: u64,
+    pub location: DivergenceLocation,
+    /// What differs, with both values when they can be formatted
+    pub detail: String,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            DivergenceLocation::Output { component_id, port } => {
+                write!(f, "cycle {}: output {}.{}: {}", self.cycle, component_id, port, self.detail)
+            }
+            DivergenceLocation::Memory { memory_id, address } => {
+                write!(f, "cycle {}: memory {}[{}]: {}", self.cycle, memory_id, address, self.detail)
+            }
+            DivergenceLocation::Model { component_id } => {
+                write!(f, "cycle {}: component {}: {}", self.cycle, component_id, self.detail)
+            }
+            DivergenceLocation::Execution => write!(f, "cycle {}: {}", self.cycle, self.detail),
+        }
+    }
+}
+
+/// Outcome of a lockstep determinism check
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeterminismReport {
+    /// Number of cycles that matched completely
+    pub cycles_checked: u64,
+    /// First divergence, if any
+    pub divergence: Option<Divergence>,
+    /// Types that were present but could not be compared (no registered equality)
+    pub unchecked_types: BTreeSet<&'static str>,
+}
+
+impl DeterminismReport {
+    /// Check if both modes agreed on every cycle and every value could be compared
+    ///
+    /// A run without a divergence but with unchecked types is not proof of
+    /// determinism; register those types with `DeterminismChecker::compare`.
+    pub fn is_deterministic(&self) -> bool {
+        self.divergence.is_none() && self.unchecked_types.is_empty()
+    }
+}
+
+/// Steps a Sequential and a Rayon engine of the same model in lockstep
+///
+/// After every cycle all output events and memory contents are compared.
+/// Values are compared through a `ValueInspector`; register custom memory
+/// and payload types with `compare::<T>()`, otherwise they are skipped and
+/// listed in `DeterminismReport::unchecked_types`.
+pub struct DeterminismChecker {
+    factory: ConfigFactory,
+    config: SimulationConfig,
+    cycles: u64,
+    inspector: ValueInspector,
+}
+
+impl DeterminismChecker {
+    /// Create a checker from a factory that builds the model for a given configuration
+    pub fn new<F>(factory: F) -> Self
+    where
+        F: Fn(SimulationConfig) -> Result<CycleEngine, String> + Send + Sync + 'static,
+    {
+        Self {
+            factory: Box::new(factory),
+            config: SimulationConfig::default(),
+            cycles: 100,
+            inspector: ValueInspector::with_primitives(),
+        }
+    }
+
+    /// Base configuration (seed, warm-up, ...); the concurrency mode is overridden
+    pub fn config(mut self, config: SimulationConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Number of cycles to run (default 100)
+    pub fn cycles(mut self, cycles: u64) -> Self {
+        self.cycles = cycles;
+        self
+    }
+
+    /// Register a memory or payload type for comparison and formatting
+    pub fn compare<T: PartialEq + fmt::Debug + 'static>(mut self) -> Self {
+        self.inspector.register::<T>();
+        self
+    }
+
+    /// Build both engines and run them in lockstep until the first divergence
+    pub fn run(&self) -> Result<DeterminismReport, String> {
+        let mut sequential = self.build(ConcurrencyMode::Sequential)?;
+        let mut parallel = self.build(ConcurrencyMode::Rayon)?;
+        let mut report = DeterminismReport {
+            cycles_checked: 0,
+            divergence: None,
+            unchecked_types: BTreeSet::new(),
+        };
+
+        if let Some(divergence) = self.compare_models(&sequential, &parallel) {
+            report.divergence = Some(divergence);
+            return Ok(report);
+        }
+
+        for _ in 0..self.cycles {
+            let cycle = sequential.current_cycle() + 1;
+            match (sequential.cycle(), parallel.cycle()) {
+                (Ok(()), Ok(())) => {}
+                (Err(a), Err(b)) if a == b => return Err(format!("Cycle {} failed in both modes: {}", cycle, a)),
+                (a, b) => {
+                    report.divergence = Some(Divergence {
+                        cycle,
+                        location: DivergenceLocation::Execution,
+                        detail: format!("sequential: {:?}, rayon: {:?}", a, b),
+                    });
+                    return Ok(report);
+                }
+            }
+
+            let divergence = self.compare_outputs(cycle, &sequential, &parallel, &mut report.unchecked_types)
+                .or_else(|| self.compare_memory(cycle, &sequential, &parallel, &mut report.unchecked_types));
+            if divergence.is_some() {
+                report.divergence = divergence;
+                return Ok(report);
+            }
+            report.cycles_checked += 1;
+        }
+        Ok(report)
+    }
+
+    fn build(&self, mode: ConcurrencyMode) -> Result<CycleEngine, String> {
+        let mut engine = (self.factory)(self.config.clone().with_concurrency(mode))?;
+        engine.build_execution_order()?;
+        Ok(engine)
+    }
+
+    fn compare_models(&self, a: &CycleEngine, b: &CycleEngine) -> Option<Divergence> {
+        let ids = |engine: &CycleEngine| -> BTreeSet<String> {
+            engine.processing_component_ids().into_iter()
+                .chain(engine.memory_component_ids())
+                .map(|id| id.id().to_string())
+                .collect()
+        };
+        let (ids_a, ids_b) = (ids(a), ids(b));
+        ids_a.symmetric_difference(&ids_b).next().map(|id| Divergence {
+            cycle: 0,
+            location: DivergenceLocation::Model { component_id: id.clone() },
+            detail: format!("present only in the {} build", if ids_a.contains(id) { "sequential" } else { "rayon" }),
+        })
+    }
+
+    fn compare_outputs(
+        &self,
+        cycle: u64,
+        a: &CycleEngine,
+        b: &CycleEngine,
+        unchecked: &mut BTreeSet<&'static str>,
+    ) -> Option<Divergence> {
+        let events = |engine: &CycleEngine| -> BTreeMap<(String, String), Event> {
+            engine.output_events().into_iter()
+                .map(|(id, port, event)| ((id.id().to_string(), port.to_string()), event.clone()))
+                .collect()
+        };
+        let (mut events_a, mut events_b) = (events(a), events(b));
+        let keys: BTreeSet<(String, String)> = events_a.keys().chain(events_b.keys()).cloned().collect();
+
+        for (component_id, port) in keys {
+            let location = DivergenceLocation::Output { component_id: component_id.clone(), port: port.clone() };
+            let key = (component_id.clone(), port.clone());
+            let detail = match (events_a.remove(&key), events_b.remove(&key)) {
+                (Some(ea), Some(eb)) => {
+                    if ea.payload.type_name() != eb.payload.type_name() {
+                        Some(format!("payload type {} vs {}", ea.payload.type_name(), eb.payload.type_name()))
+                    } else if ea.timestamp != eb.timestamp {
+                        Some(format!("timestamp {} vs {}", ea.timestamp, eb.timestamp))
+                    } else {
+                        match self.inspector.equals(ea.payload.as_any(), eb.payload.as_any()) {
+                            Some(true) => None,
+                            Some(false) => Some(self.describe(ea.payload.as_any(), eb.payload.as_any())),
+                            None => {
+                                unchecked.insert(ea.payload.type_name());
+                                None
+                            }
+                        }
+                    }
+                }
+                (Some(_), None) => Some("emitted only in sequential mode".to_string()),
+                (None, Some(_)) => Some("emitted only in rayon mode".to_string()),
+                (None, None) => None,
+            };
+            if let Some(detail) = detail {
+                return Some(Divergence { cycle, location, detail });
+            }
+        }
+        None
+    }
+
+    fn compare_memory(
+        &self,
+        cycle: u64,
+        a: &CycleEngine,
+        b: &CycleEngine,
+        unchecked: &mut BTreeSet<&'static str>,
+    ) -> Option<Divergence> {
+        for memory_id in a.memory_component_ids() {
+            let addresses_a = a.memory_addresses(memory_id).unwrap_or_default();
+            let addresses_b = b.memory_addresses(memory_id).unwrap_or_default();
+            let addresses: BTreeSet<&str> = addresses_a.iter().chain(&addresses_b)
+                .map(|info| info.address.as_str())
+                .collect();
+
+            for address in addresses {
+                let location = DivergenceLocation::Memory {
+                    memory_id: memory_id.id().to_string(),
+                    address: address.to_string(),
+                };
+                let detail = match (a.read_memory_any(memory_id, address), b.read_memory_any(memory_id, address)) {
+                    (Some(va), Some(vb)) => match self.inspector.equals(va.as_ref(), vb.as_ref()) {
+                        Some(true) => None,
+                        Some(false) => Some(self.describe(va.as_ref(), vb.as_ref())),
+                        None => {
+                            if let Some(info) = addresses_a.iter().find(|info| info.address == address) {
+                                unchecked.insert(info.type_name);
+                            }
+                            None
+                        }
+                    },
+                    (Some(_), None) => Some("written only in sequential mode".to_string()),
+                    (None, Some(_)) => Some("written only in rayon mode".to_string()),
+                    (None, None) => None,
+                };
+                if let Some(detail) = detail {
+                    return Some(Divergence { cycle, location, detail });
+                }
+            }
+        }
+        None
+    }
+
+    fn describe(&self, a: &dyn std::any::Any, b: &dyn std::any::Any) -> String {
+        match (self.inspector.format(a), self.inspector.format(b)) {
+            (Some(a), Some(b)) => format!("sequential {} vs rayon {}", a, b),
+            _ => "values differ".to_string(),
+        }
+    }
+}
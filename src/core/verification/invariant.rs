@@ -0,0 +1,97 @@
+use crate::core::execution::CycleEngine;
+use crate::core::types::ComponentId;
+use crate::core::verification::monitor::{Monitor, Observation};
+use std::fmt::Debug;
+
+type CheckFn = Box<dyn Fn(&CycleEngine) -> Result<Observation, String> + Send + Sync>;
+
+/// Named condition that must hold at the end of every cycle
+///
+/// ```ignore
+/// engine.add_monitor(
+///     Invariant::for_each_memory("fifo within capacity", |fifo: &FIFOMemory| {
+///         fifo.data_count >= 0 && fifo.data_count <= fifo.capacity
+///     }),
+///     OnViolation::Abort,
+/// )?;
+/// ```
+pub struct Invariant {
+    name: String,
+    check: CheckFn,
+}
+
+impl Invariant {
+    /// Invariant over the whole engine state
+    pub fn new<F>(name: &str, check: F) -> Self
+    where
+        F: Fn(&CycleEngine) -> Result<Observation, String> + Send + Sync + 'static,
+    {
+        Self { name: name.to_string(), check: Box::new(check) }
+    }
+
+    /// Invariant on the value at one memory address; an empty address holds
+    pub fn memory<T, F>(name: &str, memory_id: ComponentId, address: &str, predicate: F) -> Self
+    where
+        T: Debug + 'static,
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        let address = address.to_string();
+        Self::new(name, move |engine| {
+            let Some(data) = engine.read_memory_any(&memory_id, &address) else {
+                return Ok(Observation::pass("<empty>"));
+            };
+            let value = data.downcast_ref::<T>()
+                .ok_or_else(|| format!("{}[{}] does not hold a {}", memory_id, address, std::any::type_name::<T>()))?;
+            let values = format!("{}[{}] = {:?}", memory_id, address, value);
+            if predicate(value) {
+                Ok(Observation::pass(values))
+            } else {
+                Ok(Observation::fail(values.clone(), values))
+            }
+        })
+    }
+
+    /// Invariant on every memory address holding a `T`, in every memory component
+    ///
+    /// Each failing address is reported as a separate violation.
+    pub fn for_each_memory<T, F>(name: &str, predicate: F) -> Self
+    where
+        T: Debug + 'static,
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        Self::new(name, move |engine| {
+            let mut checked = 0;
+            let mut failures = Vec::new();
+            for memory_id in engine.memory_component_ids() {
+                for info in engine.memory_addresses(memory_id)? {
+                    let Some(data) = engine.read_memory_any(memory_id, &info.address) else {
+                        continue;
+                    };
+                    let Some(value) = data.downcast_ref::<T>() else {
+                        continue;
+                    };
+                    checked += 1;
+                    if !predicate(value) {
+                        failures.push(format!("{}[{}] = {:?}", memory_id, info.address, value));
+                    }
+                }
+            }
+            let values = if failures.is_empty() {
+                format!("{} values hold", checked)
+            } else {
+                failures.join("; ")
+            };
+            Ok(Observation { values, violations: failures })
+        })
+    }
+}
+
+impl Monitor for Invariant {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&mut self, engine: &CycleEngine) -> Result<Observation, String> {
+        (self.check)(engine)
+    }
+}
@@ -0,0 +1,10 @@
+pub mod determinism;
+pub mod monitor;
+pub mod invariant;
+pub mod temporal;
+
+// Re-export commonly used types
+pub use determinism::{DeterminismChecker, DeterminismReport, Divergence, DivergenceLocation};
+pub use monitor::{Monitor, MonitorState, Observation, OnViolation, Violation, MonitorSummary, MonitorReport};
+pub use invariant::Invariant;
+pub use temporal::ResponseProperty;
@@ -0,0 +1,295 @@
+use crate::core::execution::CycleEngine;
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+
+/// State a monitor carries between cycles, as saved in time-travel snapshots
+pub type MonitorState = Arc<dyn Any + Send + Sync>;
+
+/// Result of checking a monitor at the end of one cycle
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Observation {
+    /// Values the monitor looked at, kept in the history window
+    pub values: String,
+    /// One entry per violation found in this cycle
+    pub violations: Vec<String>,
+}
+
+impl Observation {
+    /// Observation with no violation
+    pub fn pass(values: impl Into<String>) -> Self {
+        Self { values: values.into(), violations: Vec::new() }
+    }
+
+    /// Observation with one violation
+    pub fn fail(values: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self { values: values.into(), violations: vec![detail.into()] }
+    }
+}
+
+/// A property checked at the end of every cycle
+///
+/// `Invariant` and `ResponseProperty` cover the common cases; implement this
+/// trait for anything else.
+pub trait Monitor: Send + Sync {
+    /// Name used in reports
+    fn name(&self) -> &str;
+
+    /// Check the engine after a cycle; `Err` means the monitor itself could not be evaluated
+    fn check(&mut self, engine: &CycleEngine) -> Result<Observation, String>;
+
+    /// Forget state carried between cycles; called when the engine is reset
+    fn reset(&mut self) {}
+
+    /// Copy of the state carried between cycles; stateless monitors keep the default
+    fn save_state(&self) -> Option<MonitorState> {
+        None
+    }
+
+    /// Return to a state from `save_state` when the engine is rewound
+    ///
+    /// `None` means the monitor had not been registered yet. The default
+    /// forgets all state.
+    fn restore_state(&mut self, _state: Option<&MonitorState>) {
+        self.reset();
+    }
+}
+
+/// What to do when a monitor reports a violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnViolation {
+    /// Fail the current `cycle()` call
+    Abort,
+    /// Record the violation and keep running
+    Count,
+}
+
+/// One reported violation with the monitor's recent history
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub monitor: String,
+    pub cycle: u64,
+    pub detail: String,
+    pub values: String,
+    /// Observed values of the preceding cycles, oldest first, ending with `cycle`
+    pub history: Vec<(u64, String)>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "'{}' violated at cycle {}: {}", self.monitor, self.cycle, self.detail)?;
+        writeln!(f, "  values: {}", self.values)?;
+        for (cycle, values) in &self.history {
+            writeln!(f, "  [{}] {}", cycle, values)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check and violation counts for one monitor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorSummary {
+    pub name: String,
+    pub action: OnViolation,
+    pub checks: u64,
+    pub violations: u64,
+}
+
+/// Summary of all monitors and the violations recorded so far
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MonitorReport {
+    pub monitors: Vec<MonitorSummary>,
+    pub violations: Vec<Violation>,
+}
+
+impl MonitorReport {
+    /// Total number of violations over all monitors
+    pub fn total_violations(&self) -> u64 {
+        self.monitors.iter().map(|monitor| monitor.violations).sum()
+    }
+}
+
+impl fmt::Display for MonitorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<32} {:>10} {:>12}", "monitor", "checks", "violations")?;
+        for monitor in &self.monitors {
+            writeln!(f, "{:<32} {:>10} {:>12}", monitor.name, monitor.checks, monitor.violations)?;
+        }
+        for violation in &self.violations {
+            write!(f, "{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+struct MonitorEntry {
+    monitor: Box<dyn Monitor>,
+    action: OnViolation,
+    history: VecDeque<(u64, String)>,
+    checks: u64,
+    violations: u64,
+}
+
+/// Saved state and results of one monitor
+#[derive(Clone)]
+struct EntrySnapshot {
+    state: Option<MonitorState>,
+    history: VecDeque<(u64, String)>,
+    checks: u64,
+    violations: u64,
+}
+
+/// Monitor state at the end of one cycle, keyed by monitor name
+#[derive(Clone, Default)]
+pub(crate) struct MonitorSnapshot {
+    entries: HashMap<String, EntrySnapshot>,
+}
+
+/// Monitors registered on an engine, checked at the end of each cycle
+pub(crate) struct MonitorSet {
+    entries: Vec<MonitorEntry>,
+    history_window: usize,
+    violations: Vec<Violation>,
+}
+
+impl MonitorSet {
+    /// Cycles of history kept per monitor by default
+    pub const DEFAULT_HISTORY_WINDOW: usize = 8;
+    /// Violations kept for the report; later ones are only counted
+    pub const MAX_RECORDED_VIOLATIONS: usize = 1000;
+
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            history_window: Self::DEFAULT_HISTORY_WINDOW,
+            violations: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, monitor: Box<dyn Monitor>, action: OnViolation) -> Result<(), String> {
+        if self.entries.iter().any(|entry| entry.monitor.name() == monitor.name()) {
+            return Err(format!("Monitor '{}' is already registered", monitor.name()));
+        }
+        self.entries.push(MonitorEntry { monitor, action, history: VecDeque::new(), checks: 0, violations: 0 });
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn set_history_window(&mut self, cycles: usize) {
+        self.history_window = cycles.max(1);
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    /// Clear results and monitor state, keeping the registered monitors
+    pub fn reset(&mut self) {
+        for entry in &mut self.entries {
+            entry.monitor.reset();
+            entry.history.clear();
+            entry.checks = 0;
+            entry.violations = 0;
+        }
+        self.violations.clear();
+    }
+
+    /// Capture monitor state and counts for a time-travel snapshot
+    pub fn snapshot(&self) -> MonitorSnapshot {
+        MonitorSnapshot {
+            entries: self.entries.iter()
+                .map(|entry| {
+                    let saved = EntrySnapshot {
+                        state: entry.monitor.save_state(),
+                        history: entry.history.clone(),
+                        checks: entry.checks,
+                        violations: entry.violations,
+                    };
+                    (entry.monitor.name().to_string(), saved)
+                })
+                .collect(),
+        }
+    }
+
+    /// Return to the end of `cycle`, as captured in `snapshot`
+    ///
+    /// Monitors registered after the snapshot start over.
+    pub fn restore(&mut self, snapshot: &MonitorSnapshot, cycle: u64) {
+        for entry in &mut self.entries {
+            match snapshot.entries.get(entry.monitor.name()) {
+                Some(saved) => {
+                    entry.monitor.restore_state(saved.state.as_ref());
+                    entry.history = saved.history.clone();
+                    entry.checks = saved.checks;
+                    entry.violations = saved.violations;
+                }
+                None => {
+                    entry.monitor.restore_state(None);
+                    entry.history.clear();
+                    entry.checks = 0;
+                    entry.violations = 0;
+                }
+            }
+        }
+        self.violations.retain(|violation| violation.cycle <= cycle);
+    }
+
+    /// Check all monitors; `Err` with the first violation of an aborting monitor
+    pub fn check(&mut self, engine: &CycleEngine) -> Result<(), String> {
+        let cycle = engine.current_cycle();
+        let mut abort = None;
+        for entry in &mut self.entries {
+            let observation = entry.monitor.check(engine)
+                .map_err(|e| format!("Monitor '{}' failed: {}", entry.monitor.name(), e))?;
+            entry.checks += 1;
+            entry.history.push_back((cycle, observation.values.clone()));
+            while entry.history.len() > self.history_window {
+                entry.history.pop_front();
+            }
+            for detail in observation.violations {
+                entry.violations += 1;
+                let violation = Violation {
+                    monitor: entry.monitor.name().to_string(),
+                    cycle,
+                    detail,
+                    values: observation.values.clone(),
+                    history: entry.history.iter().cloned().collect(),
+                };
+                if entry.action == OnViolation::Abort && abort.is_none() {
+                    abort = Some(violation.to_string());
+                }
+                if self.violations.len() < Self::MAX_RECORDED_VIOLATIONS {
+                    self.violations.push(violation);
+                }
+            }
+        }
+        match abort {
+            Some(violation) => Err(format!("Monitor violation: {}", violation.trim_end())),
+            None => Ok(()),
+        }
+    }
+
+    pub fn report(&self) -> MonitorReport {
+        MonitorReport {
+            monitors: self.entries.iter()
+                .map(|entry| MonitorSummary {
+                    name: entry.monitor.name().to_string(),
+                    action: entry.action,
+                    checks: entry.checks,
+                    violations: entry.violations,
+                })
+                .collect(),
+            violations: self.violations.clone(),
+        }
+    }
+}
+
+impl Default for MonitorSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,127 @@
+use crate::core::execution::CycleEngine;
+use crate::core::verification::monitor::{Monitor, MonitorState, Observation};
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+type KeysFn = Box<dyn Fn(&CycleEngine) -> Vec<String> + Send + Sync>;
+
+/// Bounded response property: every trigger is answered within `within` cycles
+///
+/// Triggers and responses are identified by keys (an order number, a request
+/// id, ...); a response discharges the oldest open trigger with the same key.
+/// A response in the same cycle as its trigger counts as served.
+pub struct ResponseProperty {
+    name: String,
+    within: u64,
+    trigger: KeysFn,
+    response: KeysFn,
+    /// A response discharges every open trigger, regardless of key
+    discharge_all: bool,
+    pending: VecDeque<(String, u64)>,
+}
+
+impl ResponseProperty {
+    /// "Whenever `trigger` holds, `response` holds within `within` cycles"
+    ///
+    /// A cycle where `response` holds serves every open trigger.
+    pub fn new<T, R>(name: &str, within: u64, trigger: T, response: R) -> Self
+    where
+        T: Fn(&CycleEngine) -> bool + Send + Sync + 'static,
+        R: Fn(&CycleEngine) -> bool + Send + Sync + 'static,
+    {
+        let mut property = Self::keyed(
+            name,
+            within,
+            move |engine| if trigger(engine) { vec![String::new()] } else { Vec::new() },
+            move |engine| if response(engine) { vec![String::new()] } else { Vec::new() },
+        );
+        property.discharge_all = true;
+        property
+    }
+
+    /// Every key returned by `trigger` must be returned by `response` within `within` cycles
+    pub fn keyed<T, R>(name: &str, within: u64, trigger: T, response: R) -> Self
+    where
+        T: Fn(&CycleEngine) -> Vec<String> + Send + Sync + 'static,
+        R: Fn(&CycleEngine) -> Vec<String> + Send + Sync + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            within,
+            trigger: Box::new(trigger),
+            response: Box::new(response),
+            discharge_all: false,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Number of triggers still waiting for a response
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    fn describe_pending(&self) -> String {
+        const SHOWN: usize = 5;
+        let mut shown: Vec<String> = self.pending.iter().take(SHOWN)
+            .map(|(key, cycle)| if key.is_empty() { format!("@{}", cycle) } else { format!("{}@{}", key, cycle) })
+            .collect();
+        if self.pending.len() > SHOWN {
+            shown.push(format!("... {} more", self.pending.len() - SHOWN));
+        }
+        format!("pending [{}]", shown.join(", "))
+    }
+}
+
+impl Monitor for ResponseProperty {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&mut self, engine: &CycleEngine) -> Result<Observation, String> {
+        let cycle = engine.current_cycle();
+        
+        // Triggers whose deadline passed without a response
+        let mut violations = Vec::new();
+        let within = self.within;
+        self.pending.retain(|(key, triggered)| {
+            if cycle.saturating_sub(*triggered) <= within {
+                return true;
+            }
+            let what = if key.is_empty() { "trigger".to_string() } else { format!("'{}'", key) };
+            violations.push(format!("{} from cycle {} not served within {} cycles", what, triggered, within));
+            false
+        });
+        
+        for key in (self.trigger)(engine) {
+            self.pending.push_back((key, cycle));
+        }
+        let responses = (self.response)(engine);
+        if self.discharge_all {
+            if !responses.is_empty() {
+                self.pending.clear();
+            }
+        } else {
+            for key in responses {
+                if let Some(index) = self.pending.iter().position(|(pending, _)| *pending == key) {
+                    self.pending.remove(index);
+                }
+            }
+        }
+        Ok(Observation { values: self.describe_pending(), violations })
+    }
+
+    fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    fn save_state(&self) -> Option<MonitorState> {
+        Some(Arc::new(self.pending.clone()))
+    }
+
+    fn restore_state(&mut self, state: Option<&MonitorState>) {
+        self.pending = state
+            .and_then(|state| state.downcast_ref::<VecDeque<(String, u64)>>())
+            .cloned()
+            .unwrap_or_default();
+    }
+}
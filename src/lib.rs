@@ -1,5 +1,6 @@
 pub mod core;
 pub mod macros;
+pub mod library;
 
 #[cfg(test)]
 mod macro_tests;
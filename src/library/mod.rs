@@ -0,0 +1,62 @@
+//! Reusable components for common modelling tasks
+//!
+//! Components are built with `impl_component!`. Those with parameters or
+//! state read and write a state value at address `STATE_ADDRESS` of the
+//! memory connected to their `state` port; `add_with_state` adds both and
+//! wires them together.
+
+pub mod sources;
+pub mod signal;
+pub mod routing;
+pub mod sink;
+
+use crate::core::builder::simulation_builder::Simulation;
+use crate::core::components::state::MemoryData;
+use crate::core::components::{Component, MemoryComponent};
+use crate::core::types::ComponentId;
+
+// Re-export commonly used types
+pub use sources::{Constant, ConstantValue, Clock, ClockState, Arrivals, ArrivalProcess, ArrivalDistribution};
+pub use signal::{Counter, CounterState, Accumulator, AccumulatorState, Comparator, CompareOp, DelayLine, DelayState};
+pub use routing::{Mux, Demux, Router, RouterState, RoutePolicy, MAX_LANES};
+pub use sink::{Sink, SinkLog, TokenSink};
+
+/// Memory address of a library component's state
+pub const STATE_ADDRESS: &str = "state";
+
+/// Add a library component and a memory holding `state`, connected to its `state` port
+///
+/// Returns the component and memory IDs.
+pub fn add_with_state<C, S>(sim: &mut Simulation, component: C, state: S) -> Result<(ComponentId, ComponentId), String>
+where
+    C: Component,
+    S: MemoryComponent + MemoryData,
+{
+    let component = sim.add_component(component);
+    let memory = sim.add_memory_component(state.clone());
+    sim.init_memory(&memory, STATE_ADDRESS, state);
+    sim.connect_memory_port(component.memory_port("state"), memory.clone())?;
+    Ok((component, memory))
+}
+
+/// Implement the memory traits for a state type that does nothing at the end of a cycle
+macro_rules! impl_state {
+    ($state:ident) => {
+        impl crate::core::components::state::MemoryData for $state {}
+
+        impl crate::core::components::Cycle for $state {
+            type Output = ();
+
+            fn cycle(&mut self) -> Option<Self::Output> {
+                None
+            }
+        }
+
+        impl_memory_component!($state, {
+            input: input,
+            output: output
+        });
+    };
+}
+
+pub(crate) use impl_state;
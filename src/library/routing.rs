@@ -0,0 +1,188 @@
+use crate::*;
+use crate::core::values::events::Event;
+use crate::core::values::traits::{EventInputs, EventOutputs};
+use super::{impl_state, STATE_ADDRESS};
+
+/// Names of the numbered ports of `Mux`, `Demux` and `Router`
+const LANES: [&str; MAX_LANES] = ["lane0", "lane1", "lane2", "lane3"];
+
+/// Number of lanes of `Mux`, `Demux` and `Router`; chain them for more
+pub const MAX_LANES: usize = 4;
+
+/// Forwards the value on the lane selected by the `u64` on `select` to `output`
+///
+/// Lanes are `lane0` to `lane3`. Nothing is emitted without a selection or
+/// when the selected lane is empty.
+pub struct Mux;
+
+impl_component!(Mux, "Mux", {
+    inputs: [select, lane0, lane1, lane2, lane3],
+    outputs: [output],
+    memory: [],
+    react: |ctx, outputs| {
+        if let Ok(select) = ctx.inputs.get::<u64>("select") {
+            let lane = LANES.get(select as usize)
+                .ok_or_else(|| format!("Mux select {} out of range 0..{}", select, LANES.len()))?;
+            if let Ok(event) = ctx.inputs.get_event(lane) {
+                outputs.emit_event("output", Event::from_typed_value(outputs.timestamp(), event.payload.clone()))?;
+            }
+        }
+        Ok(())
+    }
+});
+
+/// Forwards the value on `input` to the lane selected by the `u64` on `select`
+///
+/// Lanes are `lane0` to `lane3`.
+pub struct Demux;
+
+impl_component!(Demux, "Demux", {
+    inputs: [select, input],
+    outputs: [lane0, lane1, lane2, lane3],
+    memory: [],
+    react: |ctx, outputs| {
+        if let (Ok(select), Ok(event)) = (ctx.inputs.get::<u64>("select"), ctx.inputs.get_event("input")) {
+            let lane = LANES.get(select as usize)
+                .ok_or_else(|| format!("Demux select {} out of range 0..{}", select, LANES.len()))?;
+            outputs.emit_event(lane, Event::from_typed_value(outputs.timestamp(), event.payload.clone()))?;
+        }
+        Ok(())
+    }
+});
+
+/// How a `Router` picks a lane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoutePolicy {
+    /// Lanes in turn
+    RoundRobin,
+    /// Lane with the fewest values routed and not yet completed, lowest lane on ties
+    LeastLoaded,
+}
+
+/// Lane choice and load of a `Router`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouterState {
+    pub policy: RoutePolicy,
+    next: usize,
+    loads: Vec<u64>,
+}
+
+impl RouterState {
+    /// Route over the first `lanes` lanes, 1 to `MAX_LANES`
+    pub fn new(policy: RoutePolicy, lanes: usize) -> Result<Self, String> {
+        if !(1..=MAX_LANES).contains(&lanes) {
+            return Err(format!("Router must have 1 to {} lanes, found {}", MAX_LANES, lanes));
+        }
+        Ok(Self { policy, next: 0, loads: vec![0; lanes] })
+    }
+
+    /// Values routed to each lane and not yet completed
+    pub fn loads(&self) -> &[u64] {
+        &self.loads
+    }
+
+    fn pick(&mut self) -> usize {
+        match self.policy {
+            RoutePolicy::RoundRobin => {
+                let lane = self.next;
+                self.next = (self.next + 1) % self.loads.len();
+                lane
+            }
+            RoutePolicy::LeastLoaded => (0..self.loads.len()).min_by_key(|lane| self.loads[*lane]).unwrap_or(0),
+        }
+    }
+}
+
+impl_state!(RouterState);
+
+/// Forwards each value on `input` to one lane picked by the policy in its state
+///
+/// Lanes are `lane0` to `lane3`; the state decides how many are used. A `u64`
+/// lane index on `done` marks one value on that lane as completed, lowering
+/// its load before the next value is routed.
+pub struct Router;
+
+impl_component!(Router, "Router", {
+    inputs: [input, done],
+    outputs: [lane0, lane1, lane2, lane3],
+    memory: [state],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "state", STATE_ADDRESS, state: RouterState);
+        let done = ctx.inputs.get::<u64>("done").ok();
+        let input = ctx.inputs.get_event("input").ok();
+        if done.is_none() && input.is_none() {
+            return Ok(());
+        }
+        if let Some(load) = done.and_then(|lane| state.loads.get_mut(lane as usize)) {
+            *load = load.saturating_sub(1);
+        }
+        if let Some(event) = input {
+            let lane = state.pick();
+            state.loads[lane] += 1;
+            outputs.emit_event(LANES[lane], Event::from_typed_value(outputs.timestamp(), event.payload.clone()))?;
+        }
+        memory_write!(ctx, "state", STATE_ADDRESS, state);
+        Ok(())
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builder::simulation_builder::Simulation;
+    use crate::core::stimulus::IteratorStimulus;
+    use crate::library::add_with_state;
+
+    fn lane_of(engine: &crate::core::execution::CycleEngine, id: &ComponentId) -> Option<usize> {
+        LANES.iter().position(|lane| engine.last_output(id, lane).is_some())
+    }
+
+    #[test]
+    fn test_mux_and_demux() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let mux = sim.add_component(Mux);
+        let demux = sim.add_component(Demux);
+        sim.add_stimulus(mux.input("select"), IteratorStimulus::new(vec![1u64, 0]))?;
+        sim.add_stimulus(mux.input("lane0"), IteratorStimulus::new(vec![10i64, 20]))?;
+        sim.add_stimulus(mux.input("lane1"), IteratorStimulus::new(vec![11i64, 21]))?;
+        sim.connect(mux.output("output"), demux.input("input"))?;
+        sim.add_stimulus(demux.input("select"), IteratorStimulus::new(vec![2u64, 3]))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+
+        engine.cycle()?;
+        assert_eq!(engine.last_output(&mux, "output").unwrap().get_payload::<i64>()?, &11);
+        assert_eq!(lane_of(&engine, &demux), Some(2));
+        engine.cycle()?;
+        assert_eq!(engine.last_output(&demux, "lane3").unwrap().get_payload::<i64>()?, &20);
+        Ok(())
+    }
+
+    #[test]
+    fn test_router_policies() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let (round_robin, _) = add_with_state(&mut sim, Router, RouterState::new(RoutePolicy::RoundRobin, 3)?)?;
+        let (least_loaded, state) = add_with_state(&mut sim, Router, RouterState::new(RoutePolicy::LeastLoaded, 2)?)?;
+        assert!(RouterState::new(RoutePolicy::RoundRobin, MAX_LANES + 1).is_err());
+        assert!(RouterState::new(RoutePolicy::RoundRobin, 0).is_err());
+        for router in [&round_robin, &least_loaded] {
+            sim.add_stimulus(router.input("input"), IteratorStimulus::new(vec![1u64; 4]))?;
+        }
+        // Lane 0 completes a value in cycles 2 and 4; index 9 names no lane and is ignored
+        sim.add_stimulus(least_loaded.input("done"), IteratorStimulus::new(vec![9u64, 0, 9, 0]))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+
+        let (mut round_robin_lanes, mut least_loaded_lanes) = (Vec::new(), Vec::new());
+        for _ in 0..4 {
+            engine.cycle()?;
+            round_robin_lanes.push(lane_of(&engine, &round_robin).unwrap());
+            least_loaded_lanes.push(lane_of(&engine, &least_loaded).unwrap());
+        }
+        assert_eq!(round_robin_lanes, vec![0, 1, 2, 0]);
+        assert_eq!(least_loaded_lanes, vec![0, 0, 1, 0]);
+        let state = engine.query_memory_component_data::<RouterState>(&state, STATE_ADDRESS)?.unwrap();
+        assert_eq!(state.loads(), &[1, 1]);
+        Ok(())
+    }
+}
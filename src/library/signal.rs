@@ -0,0 +1,235 @@
+use crate::*;
+use crate::core::values::events::Event;
+use crate::core::values::traits::{EventInputs, EventOutputs};
+use crate::core::values::typed_value::TypedValue;
+use super::{impl_state, STATE_ADDRESS};
+use std::collections::VecDeque;
+
+/// Count held by a `Counter`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CounterState {
+    pub count: u64,
+    /// Wrap the count to zero on reaching this value
+    pub modulus: Option<u64>,
+}
+
+impl CounterState {
+    /// Counter starting at zero that never wraps
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap the count to zero on reaching `modulus`
+    pub fn with_modulus(mut self, modulus: u64) -> Self {
+        self.modulus = Some(modulus);
+        self
+    }
+}
+
+impl_state!(CounterState);
+
+/// Counts cycles with an event on `increment` and emits the count as a `u64` on `count`
+///
+/// A `bool` increment counts only when `true`. An event on `reset` clears the
+/// count before the increment is applied.
+pub struct Counter;
+
+impl_component!(Counter, "Counter", {
+    inputs: [increment, reset],
+    outputs: [count],
+    memory: [state],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "state", STATE_ADDRESS, state: CounterState);
+        if ctx.inputs.has_input("reset") {
+            state.count = 0;
+        }
+        if ctx.inputs.has_input("increment") && ctx.inputs.get::<bool>("increment").unwrap_or(true) {
+            state.count += 1;
+            if state.modulus.is_some_and(|modulus| state.count >= modulus) {
+                state.count = 0;
+            }
+        }
+        outputs.set("count", state.count)?;
+        memory_write!(ctx, "state", STATE_ADDRESS, state);
+        Ok(())
+    }
+});
+
+/// Running total of an `Accumulator`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccumulatorState {
+    pub total: f64,
+    /// Number of values added since the last reset
+    pub samples: u64,
+}
+
+impl AccumulatorState {
+    /// Mean of the values added since the last reset
+    pub fn mean(&self) -> Option<f64> {
+        (self.samples > 0).then(|| self.total / self.samples as f64)
+    }
+}
+
+impl_state!(AccumulatorState);
+
+/// Adds each `f64` on `value` to a running total emitted on `total`
+///
+/// An event on `reset` clears the total before the value is added.
+pub struct Accumulator;
+
+impl_component!(Accumulator, "Accumulator", {
+    inputs: [value, reset],
+    outputs: [total],
+    memory: [state],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "state", STATE_ADDRESS, state: AccumulatorState);
+        if ctx.inputs.has_input("reset") {
+            state = AccumulatorState::default();
+        }
+        if let Ok(value) = ctx.inputs.get::<f64>("value") {
+            state.total += value;
+            state.samples += 1;
+        }
+        outputs.set("total", state.total)?;
+        memory_write!(ctx, "state", STATE_ADDRESS, state);
+        Ok(())
+    }
+});
+
+/// Relation tested by a `Comparator`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+    GreaterOrEqual,
+    Greater,
+}
+
+impl CompareOp {
+    /// Check if `a` relates to `b`
+    pub fn apply(&self, a: f64, b: f64) -> bool {
+        match self {
+            CompareOp::Less => a < b,
+            CompareOp::LessOrEqual => a <= b,
+            CompareOp::Equal => a == b,
+            CompareOp::NotEqual => a != b,
+            CompareOp::GreaterOrEqual => a >= b,
+            CompareOp::Greater => a > b,
+        }
+    }
+}
+
+impl_state!(CompareOp);
+
+/// Emits on `result` whether the `f64` on `a` relates to the one on `b` by the operator in its state
+///
+/// Nothing is emitted unless both inputs have a value.
+pub struct Comparator;
+
+impl_component!(Comparator, "Comparator", {
+    inputs: [a, b],
+    outputs: [result],
+    memory: [state],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "state", STATE_ADDRESS, op: CompareOp);
+        if let (Ok(a), Ok(b)) = (ctx.inputs.get::<f64>("a"), ctx.inputs.get::<f64>("b")) {
+            outputs.set("result", op.apply(a, b))?;
+        }
+        Ok(())
+    }
+});
+
+/// Values in flight through a `DelayLine`
+#[derive(Debug, Clone)]
+pub struct DelayState {
+    delay: usize,
+    slots: VecDeque<Option<TypedValue>>,
+}
+
+impl DelayState {
+    /// Delay values by `delay` cycles
+    pub fn new(delay: usize) -> Self {
+        Self { delay, slots: VecDeque::new() }
+    }
+
+    /// Number of cycles values are delayed by
+    pub fn delay(&self) -> usize {
+        self.delay
+    }
+
+    /// Number of values in flight
+    pub fn in_flight(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_some()).count()
+    }
+}
+
+impl_state!(DelayState);
+
+/// Emits each value received on `input` on `output` a fixed number of cycles later
+///
+/// Values of any type are forwarded.
+pub struct DelayLine;
+
+impl_component!(DelayLine, "DelayLine", {
+    inputs: [input],
+    outputs: [output],
+    memory: [state],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "state", STATE_ADDRESS, state: DelayState);
+        let arrived = ctx.inputs.get_event("input").ok().map(|event| event.payload.clone());
+        if arrived.is_some() || state.in_flight() > 0 {
+            state.slots.push_back(arrived);
+            if state.slots.len() > state.delay {
+                if let Some(Some(value)) = state.slots.pop_front() {
+                    outputs.emit_event("output", Event::from_typed_value(outputs.timestamp(), value))?;
+                }
+            }
+            memory_write!(ctx, "state", STATE_ADDRESS, state);
+        }
+        Ok(())
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builder::simulation_builder::Simulation;
+    use crate::core::stimulus::IteratorStimulus;
+    use crate::library::add_with_state;
+
+    #[test]
+    fn test_signal_components() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let (counter, _) = add_with_state(&mut sim, Counter, CounterState::new().with_modulus(3))?;
+        let (accumulator, accumulator_state) = add_with_state(&mut sim, Accumulator, AccumulatorState::default())?;
+        let (comparator, _) = add_with_state(&mut sim, Comparator, CompareOp::Greater)?;
+        let (delay, _) = add_with_state(&mut sim, DelayLine, DelayState::new(2))?;
+        sim.add_stimulus(counter.input("increment"), IteratorStimulus::new(vec![true, false, true, true, true]))?;
+        sim.add_stimulus(accumulator.input("value"), IteratorStimulus::new(vec![1.0f64, 2.0, 3.0, 4.0]))?;
+        sim.add_stimulus(comparator.input("a"), IteratorStimulus::new(vec![1.0f64, 5.0]))?;
+        sim.add_stimulus(comparator.input("b"), IteratorStimulus::new(vec![2.0f64, 2.0]))?;
+        sim.add_stimulus(delay.input("input"), IteratorStimulus::new(vec!["a".to_string(), "b".to_string()]))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+
+        let (mut counts, mut totals, mut results, mut delayed) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        for _ in 0..5 {
+            engine.cycle()?;
+            counts.push(*engine.last_output(&counter, "count").unwrap().get_payload::<u64>()?);
+            totals.push(*engine.last_output(&accumulator, "total").unwrap().get_payload::<f64>()?);
+            if let Some(event) = engine.last_output(&comparator, "result") {
+                results.push(*event.get_payload::<bool>()?);
+            }
+            delayed.push(engine.last_output(&delay, "output").map(|e| e.get_payload::<String>().cloned()).transpose()?);
+        }
+        assert_eq!(counts, vec![1, 1, 2, 0, 1]);
+        assert_eq!(totals, vec![1.0, 3.0, 6.0, 10.0, 10.0]);
+        assert_eq!(results, vec![false, true]);
+        assert_eq!(delayed, vec![None, None, Some("a".to_string()), Some("b".to_string()), None]);
+        let state = engine.query_memory_component_data::<AccumulatorState>(&accumulator_state, STATE_ADDRESS)?.unwrap();
+        assert_eq!(state.mean(), Some(2.5));
+        Ok(())
+    }
+}
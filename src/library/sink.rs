@@ -0,0 +1,130 @@
+use crate::*;
+use crate::core::entities::{Token, TokenLog};
+use crate::core::memory::AppendLog;
+use crate::core::values::events::Event;
+use crate::core::values::traits::EventInputs;
+use super::{impl_state, STATE_ADDRESS};
+use std::collections::VecDeque;
+
+/// Events received by a `Sink`
+///
+/// Without a limit the events are shared between copies of the log, so it
+/// stays cheap to snapshot as it grows.
+#[derive(Debug, Clone, Default)]
+pub struct SinkLog {
+    /// Keep only the most recent events
+    pub limit: Option<usize>,
+    received: u64,
+    /// Latest events while limited
+    recent: VecDeque<Event>,
+    /// Every event while unlimited
+    all: AppendLog<Event>,
+}
+
+impl SinkLog {
+    /// Log keeping every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Log keeping only the last `limit` events
+    pub fn with_limit(limit: usize) -> Self {
+        Self { limit: Some(limit), ..Self::default() }
+    }
+
+    /// Number of events received, including those no longer kept
+    pub fn received(&self) -> u64 {
+        self.received
+    }
+
+    /// Kept events, oldest first; timestamps are the cycles they were received in
+    pub fn events(&self) -> impl Iterator<Item = &Event> {
+        self.all.iter().chain(&self.recent)
+    }
+
+    /// Payloads of the kept events, failing on any of another type
+    pub fn values<T: Clone + 'static>(&self) -> Result<Vec<T>, String> {
+        self.events().map(|event| event.get_payload::<T>().cloned()).collect()
+    }
+
+    fn receive(&mut self, event: Event) {
+        self.received += 1;
+        match self.limit {
+            Some(limit) => {
+                self.recent.push_back(event);
+                while self.recent.len() > limit {
+                    self.recent.pop_front();
+                }
+            }
+            None => self.all.push(event),
+        }
+    }
+}
+
+impl_state!(SinkLog);
+
+/// Consumes every value on `input`, logging it in its state
+pub struct Sink;
+
+impl_component!(Sink, "Sink", {
+    inputs: [input],
+    outputs: [],
+    memory: [state],
+    react: |ctx, outputs| {
+        if let Ok(event) = ctx.inputs.get_event("input") {
+            memory_read_or_error!(ctx, "state", STATE_ADDRESS, log: SinkLog);
+            log.receive(Event::from_typed_value(outputs.timestamp(), event.payload.clone()));
+            memory_write!(ctx, "state", STATE_ADDRESS, log);
+        }
+        Ok(())
+    }
+});
+
+/// Retires each `Token` on `token` in the `TokenLog` held in its state
+pub struct TokenSink;
+
+impl_component!(TokenSink, "TokenSink", {
+    inputs: [token],
+    outputs: [],
+    memory: [state],
+    react: |ctx, outputs| {
+        if let Ok(mut token) = ctx.inputs.get::<Token>("token") {
+            memory_read_or_error!(ctx, "state", STATE_ADDRESS, log: TokenLog);
+            token.retire(outputs.timestamp());
+            log.record(token);
+            memory_write!(ctx, "state", STATE_ADDRESS, log);
+        }
+        Ok(())
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builder::simulation_builder::Simulation;
+    use crate::core::stimulus::IteratorStimulus;
+    use crate::library::add_with_state;
+
+    #[test]
+    fn test_sink_keeps_latest_events() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let (sink, log) = add_with_state(&mut sim, Sink, SinkLog::with_limit(2))?;
+        let (all_sink, all) = add_with_state(&mut sim, Sink, SinkLog::new())?;
+        sim.add_stimulus(sink.input("input"), IteratorStimulus::new(vec![1i64, 2, 3]))?;
+        sim.add_stimulus(all_sink.input("input"), IteratorStimulus::new(vec![1i64, 2, 3]))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        for _ in 0..4 {
+            engine.cycle()?;
+        }
+
+        let log = engine.query_memory_component_data::<SinkLog>(&log, STATE_ADDRESS)?.unwrap();
+        assert_eq!(log.received(), 3);
+        assert_eq!(log.values::<i64>()?, vec![2, 3]);
+        assert_eq!(log.events().map(|event| event.timestamp).collect::<Vec<_>>(), vec![2, 3]);
+        assert!(log.values::<f64>().is_err());
+        let all = engine.query_memory_component_data::<SinkLog>(&all, STATE_ADDRESS)?.unwrap();
+        assert_eq!(all.values::<i64>()?, vec![1, 2, 3]);
+        Ok(())
+    }
+}
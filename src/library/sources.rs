@@ -0,0 +1,171 @@
+use crate::*;
+use crate::core::values::events::Event;
+use crate::core::values::traits::EventOutputs;
+use crate::core::values::typed_value::TypedValue;
+use super::{impl_state, STATE_ADDRESS};
+
+/// Value emitted by `Constant`
+#[derive(Debug, Clone)]
+pub struct ConstantValue {
+    value: TypedValue,
+}
+
+impl ConstantValue {
+    /// Emit `value` every cycle
+    pub fn new<T: Send + Sync + Clone + 'static>(value: T) -> Self {
+        Self { value: TypedValue::new(value) }
+    }
+}
+
+impl_state!(ConstantValue);
+
+/// Emits the value held in its state on `value` every cycle
+pub struct Constant;
+
+impl_component!(Constant, "Constant", {
+    inputs: [],
+    outputs: [value],
+    memory: [state],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "state", STATE_ADDRESS, state: ConstantValue);
+        let event = Event::from_typed_value(outputs.timestamp(), state.value.clone());
+        outputs.emit_event("value", event)?;
+        Ok(())
+    }
+});
+
+/// Period and duty of a `Clock`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClockState {
+    pub period: u64,
+    /// Cycles per period the clock is high
+    pub high: u64,
+    elapsed: u64,
+}
+
+impl ClockState {
+    /// Clock high for the first half of every `period` cycles
+    pub fn new(period: u64) -> Self {
+        Self { period, high: period.div_ceil(2), elapsed: 0 }
+    }
+
+    /// Set the number of cycles per period the clock is high
+    pub fn with_high(mut self, high: u64) -> Self {
+        self.high = high;
+        self
+    }
+}
+
+impl_state!(ClockState);
+
+/// Emits a bool on `tick` every cycle: `true` for the first `high` cycles of each period
+pub struct Clock;
+
+impl_component!(Clock, "Clock", {
+    inputs: [],
+    outputs: [tick],
+    memory: [state],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "state", STATE_ADDRESS, state: ClockState);
+        if state.period == 0 {
+            return Err("Clock period must be at least 1".to_string());
+        }
+        outputs.set("tick", state.elapsed < state.high)?;
+        state.elapsed = (state.elapsed + 1) % state.period;
+        memory_write!(ctx, "state", STATE_ADDRESS, state);
+        Ok(())
+    }
+});
+
+/// Distribution of the number of arrivals per cycle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArrivalDistribution {
+    /// One arrival with probability `p`
+    Bernoulli(f64),
+    /// Poisson-distributed count with the given mean
+    Poisson(f64),
+}
+
+/// Arrival process of an `Arrivals` source
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrivalProcess {
+    pub distribution: ArrivalDistribution,
+    total: u64,
+}
+
+impl ArrivalProcess {
+    /// One arrival per cycle with probability `p`
+    pub fn bernoulli(p: f64) -> Self {
+        Self { distribution: ArrivalDistribution::Bernoulli(p), total: 0 }
+    }
+
+    /// Poisson-distributed arrivals with mean `rate` per cycle
+    pub fn poisson(rate: f64) -> Self {
+        Self { distribution: ArrivalDistribution::Poisson(rate), total: 0 }
+    }
+
+    /// Number of arrivals generated so far
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+impl_state!(ArrivalProcess);
+
+/// Emits the number of arrivals as a `u64` on `arrivals` in cycles with at least one
+///
+/// Samples are drawn from the component's random stream.
+pub struct Arrivals;
+
+impl_component!(Arrivals, "Arrivals", {
+    inputs: [],
+    outputs: [arrivals],
+    memory: [state],
+    react: |ctx, outputs| {
+        memory_read_or_error!(ctx, "state", STATE_ADDRESS, state: ArrivalProcess);
+        let count = match state.distribution {
+            ArrivalDistribution::Bernoulli(p) => ctx.rng.chance(p)? as u64,
+            ArrivalDistribution::Poisson(rate) => ctx.rng.poisson(rate)?,
+        };
+        if count > 0 {
+            outputs.set("arrivals", count)?;
+            state.total += count;
+            memory_write!(ctx, "state", STATE_ADDRESS, state);
+        }
+        Ok(())
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builder::simulation_builder::Simulation;
+    use crate::library::add_with_state;
+
+    #[test]
+    fn test_sources() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let (constant, _) = add_with_state(&mut sim, Constant, ConstantValue::new(7i64))?;
+        let (clock, _) = add_with_state(&mut sim, Clock, ClockState::new(3))?;
+        let (bernoulli, bernoulli_state) = add_with_state(&mut sim, Arrivals, ArrivalProcess::bernoulli(0.5))?;
+        let (poisson, poisson_state) = add_with_state(&mut sim, Arrivals, ArrivalProcess::poisson(2.0))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+
+        let (mut ticks, mut bernoulli_total, mut poisson_total) = (Vec::new(), 0, 0);
+        for _ in 0..200 {
+            engine.cycle()?;
+            assert_eq!(engine.last_output(&constant, "value").unwrap().get_payload::<i64>()?, &7);
+            ticks.push(*engine.last_output(&clock, "tick").unwrap().get_payload::<bool>()?);
+            bernoulli_total += engine.last_output(&bernoulli, "arrivals").map_or(Ok(0), |e| e.get_payload::<u64>().copied())?;
+            poisson_total += engine.last_output(&poisson, "arrivals").map_or(Ok(0), |e| e.get_payload::<u64>().copied())?;
+        }
+        assert_eq!(ticks[..6], [true, true, false, true, true, false]);
+        let bernoulli_state = engine.query_memory_component_data::<ArrivalProcess>(&bernoulli_state, STATE_ADDRESS)?.unwrap();
+        let poisson_state = engine.query_memory_component_data::<ArrivalProcess>(&poisson_state, STATE_ADDRESS)?.unwrap();
+        assert_eq!((bernoulli_state.total(), poisson_state.total()), (bernoulli_total, poisson_total));
+        assert!((60..=140).contains(&bernoulli_total));
+        assert!((300..=500).contains(&poisson_total));
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 #[macro_export]
 macro_rules! memory_read {
     ($ctx:expr, $port:expr, $address:expr, $var:ident: $type:ty = $default:expr) => {
+        #[allow(unused_mut)]
         let mut $var: $type = $ctx.memory.read::<$type>($port, $address)
             .unwrap_or(Some($default))
             .unwrap_or($default);
@@ -31,6 +32,7 @@ macro_rules! memory_read {
 #[macro_export]
 macro_rules! memory_read_or_error {
     ($ctx:expr, $port:expr, $address:expr, $var:ident: $type:ty) => {
+        #[allow(unused_mut)]
         let mut $var: $type = $ctx.memory.read::<$type>($port, $address)
             .map_err(|e| format!("Failed to read memory port '{}' address '{}': {}", $port, $address, e))?
             .ok_or_else(|| format!("No value found at memory port '{}' address '{}'", $port, $address))?;
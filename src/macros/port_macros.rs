@@ -79,22 +79,23 @@ macro_rules! port_definitions {
         $(memory: [$($memory:ident),* $(,)?],)?
     ) => {
         {
-            let mut ports = Vec::new();
-            $(
+            let ports: Vec<(String, $crate::core::components::types::PortType)> = vec![
                 $(
-                    ports.push((stringify!($input).to_string(), $crate::core::components::types::PortType::Input));
-                )*
-            )?
-            $(
+                    $(
+                        (stringify!($input).to_string(), $crate::core::components::types::PortType::Input),
+                    )*
+                )?
                 $(
-                    ports.push((stringify!($output).to_string(), $crate::core::components::types::PortType::Output));
-                )*
-            )?
-            $(
+                    $(
+                        (stringify!($output).to_string(), $crate::core::components::types::PortType::Output),
+                    )*
+                )?
                 $(
-                    ports.push((stringify!($memory).to_string(), $crate::core::components::types::PortType::Memory));
-                )*
-            )?
+                    $(
+                        (stringify!($memory).to_string(), $crate::core::components::types::PortType::Memory),
+                    )*
+                )?
+            ];
             ports
         }
     };
@@ -1,3 +1,105 @@
-fn main() {
-    println!("Event-based simulator initialized");
+//! Interactive cycle-level debugger
+//!
+//! Usage: `rsim [model] [--seed N]`. Type `help` at the prompt for commands.
+
+#[allow(dead_code)]
+#[path = "../examples/mc_simulation/components/mod.rs"]
+mod components;
+#[allow(dead_code)]
+#[path = "../examples/mc_simulation/simulation_builder.rs"]
+mod simulation_builder;
+
+use components::component_states::*;
+use components::fifo::FIFOData;
+use components::fifo_memory::FIFOMemory;
+use rsim::core::builder::simulation_builder::Simulation;
+use rsim::core::debugger::Debugger;
+use rsim::core::execution::{CycleEngine, SimulationConfig};
+use simulation_builder::McSimulationBuilder;
+use std::io::{BufRead, Write};
+
+/// Snapshot interval and count for rewinding with `back` and `goto`
+const TIME_TRAVEL_INTERVAL: u64 = 100;
+const TIME_TRAVEL_CAPACITY: usize = 64;
+
+/// Registered example models: (name, description, builder taking the master seed)
+type ModelBuilder = fn(u64) -> Result<Simulation, String>;
+const MODELS: &[(&str, &str, ModelBuilder)] = &[
+    ("mc-small", "McDonald's production line, 3 of each station", |seed| mc_model(3, seed)),
+    ("mc-medium", "McDonald's production line, 5 of each station", |seed| mc_model(5, seed)),
+    ("mc-large", "McDonald's production line, 10 of each station", |seed| mc_model(10, seed)),
+];
+
+fn mc_model(stations: usize, seed: u64) -> Result<Simulation, String> {
+    let (sim, _) = McSimulationBuilder::new()
+        .component_counts(stations, stations, stations, stations)
+        .build_with_config(SimulationConfig::default().with_seed(seed))?;
+    Ok(sim)
+}
+
+fn usage() -> String {
+    let mut text = String::from("Usage: rsim [model] [--seed N]\n\nModels:\n");
+    for (name, description, _) in MODELS {
+        text.push_str(&format!("  {:<12} {}\n", name, description));
+    }
+    text
+}
+
+fn load_model(name: &str, seed: u64) -> Result<CycleEngine, String> {
+    let (_, _, build) = MODELS.iter()
+        .find(|(model, _, _)| *model == name)
+        .ok_or_else(|| format!("Unknown model '{}'\n\n{}", name, usage()))?;
+    let mut engine = build(seed)?.build()?;
+    engine.build_execution_order()?;
+    Ok(engine)
+}
+
+fn main() -> Result<(), String> {
+    let mut model = "mc-small".to_string();
+    let mut seed = 0;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed needs a value")?;
+                seed = value.parse().map_err(|_| format!("Invalid seed '{}'", value))?;
+            }
+            "-h" | "--help" => {
+                print!("{}", usage());
+                return Ok(());
+            }
+            _ => model = arg,
+        }
+    }
+
+    let mut engine = load_model(&model, seed)?;
+    engine.enable_time_travel(TIME_TRAVEL_INTERVAL, TIME_TRAVEL_CAPACITY);
+    let mut debugger = Debugger::new(engine);
+    debugger.inspector_mut()
+        .register_debug::<FIFOMemory>()
+        .register_debug::<FIFOData>()
+        .register_debug::<BakerState>()
+        .register_debug::<FryerState>()
+        .register_debug::<AssemblerState>()
+        .register_debug::<CustomerState>();
+
+    println!("Loaded '{}' ({} components). Type 'help' for commands.", model, debugger.engine().component_ids().len());
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("(rsim) ");
+        std::io::stdout().flush().map_err(|e| e.to_string())?;
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line.map_err(|e| e.to_string())?;
+        if matches!(line.trim(), "quit" | "q" | "exit") {
+            break;
+        }
+        match debugger.execute(&line) {
+            Ok(output) => print!("{}", output),
+            Err(error) => println!("Error: {}", error),
+        }
+    }
+    Ok(())
 }
@@ -4,6 +4,16 @@ use rsim::core::{
     components::module::{ProcessorModule, PortSpec},
     components::state::MemoryData,
     values::traits::{EventInputs, EventOutputs},
+    stimulus::{ClosureStimulus, IteratorStimulus},
+    probes::Probe,
+    experiments::{ReplicationRunner, ParameterSweep, DesignBuilder, Parameter},
+    verification::{DeterminismChecker, DivergenceLocation, Invariant, ResponseProperty, OnViolation, Observation},
+    replay::InputLog,
+    debugger::{Debugger, StopReason, WatchCondition},
+    execution::{SimulationConfig, ConcurrencyMode, ProfileKind, ClockDomain},
+    memory::{Fifo, Channel, CHANNEL_ADDRESS},
+    queueing::{Station, StationResource, Discipline, ServiceTime, RESOURCE_ADDRESS},
+    entities::{Token, TokenLog, AttributeValue},
 };
 
 /// Test component: Adder from rsim_core_api.md
@@ -96,7 +106,7 @@ impl Component for MemoryProcessor {
 }
 
 /// Test component: Buffer memory component from rsim_core_api.md
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
 struct Buffer {
     data: i64,
 }
@@ -315,6 +325,372 @@ impl Component for Calculator {
     }
 }
 
+/// Test component: writes a Buffer holding the current cycle into memory
+struct BufferWriter;
+
+impl Component for BufferWriter {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("buffer".to_string(), PortType::Memory),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "BufferWriter", 
+            vec![], 
+            vec![], 
+            vec![PortSpec::memory("buffer")],
+            |ctx, _outputs| {
+                let previous = ctx.memory.read::<Buffer>("buffer", "latest")?.map(|b| b.data).unwrap_or(0);
+                ctx.memory.write("buffer", "latest", Buffer { data: previous + 1 })?;
+                ctx.memory.write("buffer", "first", Buffer { data: 1 })?;
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: records its input as a wait-time sample and tracks a queue level
+struct WaitTracker;
+
+impl Component for WaitTracker {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("wait".to_string(), PortType::Input),
+            ("buffer".to_string(), PortType::Memory),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "WaitTracker", 
+            vec![PortSpec::input("wait")], 
+            vec![], 
+            vec![PortSpec::memory("buffer")],
+            |ctx, _outputs| {
+                let queue = ctx.memory.read::<Buffer>("buffer", "queue")?.map(|b| b.data).unwrap_or(0);
+                ctx.memory.write("buffer", "queue", Buffer { data: (queue + 1) % 3 })?;
+                if let Ok(wait) = ctx.inputs.get::<f64>("wait") {
+                    ctx.stats.increment("arrivals");
+                    ctx.stats.record("wait_time", wait);
+                }
+                ctx.stats.set_level("queue_length", queue as f64);
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: emits a draw from its per-component random stream
+struct NoiseSource;
+
+impl Component for NoiseSource {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("value".to_string(), PortType::Output),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "NoiseSource", 
+            vec![], 
+            vec![PortSpec::output("value")], 
+            vec![],
+            |ctx, outputs| {
+                let value = ctx.rng.uniform(0.0, 1.0)?;
+                outputs.set("value", value)?;
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: reports whether it runs on a worker thread (deliberately non-deterministic)
+struct ThreadReporter;
+
+impl Component for ThreadReporter {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("on_worker".to_string(), PortType::Output),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "ThreadReporter", 
+            vec![], 
+            vec![PortSpec::output("on_worker")], 
+            vec![],
+            |_ctx, outputs| {
+                outputs.set("on_worker", rayon::current_thread_index().is_some())?;
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: mixes a stimulus, an external clock reading and a random draw
+struct Sampler;
+
+/// Live external source for `Sampler`; never repeats a value
+static LIVE_CLOCK: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+impl Component for Sampler {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("input".to_string(), PortType::Input),
+            ("value".to_string(), PortType::Output),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "Sampler", 
+            vec![PortSpec::input("input")], 
+            vec![PortSpec::output("value")], 
+            vec![],
+            |ctx, outputs| {
+                let input: f64 = ctx.inputs.get("input").unwrap_or(0.0);
+                let now: u64 = ctx.external.capture("now", || {
+                    LIVE_CLOCK.fetch_add(1, std::sync::atomic::Ordering::SeqCst) * 1000
+                })?;
+                let noise = ctx.rng.uniform(0.0, 1.0)?;
+                outputs.set("value", input + now as f64 + noise)?;
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: forwards a bool "enable" input to its "open" output
+struct ShiftGate;
+
+impl Component for ShiftGate {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("enable".to_string(), PortType::Input),
+            ("open".to_string(), PortType::Output),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "ShiftGate", 
+            vec![PortSpec::input("enable")], 
+            vec![PortSpec::output("open")], 
+            vec![],
+            |ctx, outputs| {
+                if let Ok(enable) = ctx.inputs.get::<bool>("enable") {
+                    outputs.set("open", enable)?;
+                }
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test payload: an order carrying its id and the cycle it was placed
+#[derive(Clone, Debug, PartialEq)]
+struct Order {
+    id: u64,
+    placed: u64,
+}
+
+impl MemoryData for Order {}
+
+/// Test component: pushes each order id it receives onto a queue
+struct OrderProducer;
+
+impl Component for OrderProducer {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("order".to_string(), PortType::Input),
+            ("queue".to_string(), PortType::Memory),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "OrderProducer", 
+            vec![PortSpec::input("order")], 
+            vec![], 
+            vec![PortSpec::memory("queue")],
+            |ctx, _outputs| {
+                if let Ok(id) = ctx.inputs.get::<u64>("order") {
+                    let mut queue = ctx.memory.read::<Fifo<Order>>("queue", "orders")?.ok_or("queue not initialized")?;
+                    queue.push(Order { id, placed: id % 100 });
+                    ctx.memory.write("queue", "orders", queue)?;
+                }
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: takes one order per cycle from a queue and reports its id
+struct OrderConsumer;
+
+impl Component for OrderConsumer {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("served".to_string(), PortType::Output),
+            ("queue".to_string(), PortType::Memory),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "OrderConsumer", 
+            vec![], 
+            vec![PortSpec::output("served")], 
+            vec![PortSpec::memory("queue")],
+            |ctx, outputs| {
+                let mut queue = ctx.memory.read::<Fifo<Order>>("queue", "orders")?.ok_or("queue not initialized")?;
+                if let Some(order) = queue.delivered(ctx.component_id.id()).first() {
+                    ctx.stats.increment("served");
+                    outputs.set("served", order.id)?;
+                }
+                queue.request_pop(ctx.component_id.id());
+                ctx.memory.write("queue", "orders", queue)?;
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: sends increasing numbers over a channel whenever it may
+struct ChannelProducer;
+
+impl Component for ChannelProducer {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("accepted".to_string(), PortType::Output),
+            ("out".to_string(), PortType::Memory),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "ChannelProducer", 
+            vec![], 
+            vec![PortSpec::output("accepted")], 
+            vec![PortSpec::memory("out")],
+            |ctx, outputs| {
+                let mut channel = ctx.memory.read::<Channel<i64>>("out", CHANNEL_ADDRESS)?.ok_or("channel not initialized")?;
+                outputs.set("accepted", channel.accepted())?;
+                let next = channel.stats().offered as i64 + 1;
+                if channel.send(next).is_ok() {
+                    ctx.memory.write("out", CHANNEL_ADDRESS, channel)?;
+                }
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: takes items from a channel in cycles its `ready` input is true
+struct ChannelConsumer;
+
+impl Component for ChannelConsumer {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("ready".to_string(), PortType::Input),
+            ("item".to_string(), PortType::Output),
+            ("in".to_string(), PortType::Memory),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "ChannelConsumer", 
+            vec![PortSpec::input("ready")], 
+            vec![PortSpec::output("item")], 
+            vec![PortSpec::memory("in")],
+            |ctx, outputs| {
+                let mut channel = ctx.memory.read::<Channel<i64>>("in", CHANNEL_ADDRESS)?.ok_or("channel not initialized")?;
+                if let Some(item) = channel.received() {
+                    outputs.set("item", *item)?;
+                }
+                if ctx.inputs.get::<bool>("ready").unwrap_or(false) {
+                    channel.set_ready();
+                    ctx.memory.write("in", CHANNEL_ADDRESS, channel)?;
+                }
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: creates a burger token for each order and queues it for bread
+struct BurgerCounter;
+
+impl Component for BurgerCounter {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("order".to_string(), PortType::Input),
+            ("queue".to_string(), PortType::Memory),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "BurgerCounter", 
+            vec![PortSpec::input("order")], 
+            vec![], 
+            vec![PortSpec::memory("queue")],
+            |ctx, outputs| {
+                if let Ok(order) = ctx.inputs.get::<i64>("order") {
+                    let cycle = outputs.timestamp();
+                    let mut burger = ctx.tokens.create("burger");
+                    burger.set("order", order);
+                    burger.enter_stage(ctx.component_id, "wait_bread", cycle);
+                    let mut queue = ctx.memory.read::<Fifo<Token>>("queue", "burgers")?.ok_or("queue not initialized")?;
+                    queue.push(burger);
+                    ctx.memory.write("queue", "burgers", queue)?;
+                }
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Test component: takes a queued burger when ready and sends it on to assembly
+struct BurgerAssembler;
+
+impl Component for BurgerAssembler {
+    fn define_ports() -> Vec<(String, PortType)> {
+        vec![
+            ("ready".to_string(), PortType::Input),
+            ("burger".to_string(), PortType::Output),
+            ("queue".to_string(), PortType::Memory),
+        ]
+    }
+    
+    fn into_module() -> ProcessorModule {
+        ProcessorModule::new(
+            "BurgerAssembler", 
+            vec![PortSpec::input("ready")], 
+            vec![PortSpec::output("burger")], 
+            vec![PortSpec::memory("queue")],
+            |ctx, outputs| {
+                let mut queue = ctx.memory.read::<Fifo<Token>>("queue", "burgers")?.ok_or("queue not initialized")?;
+                if let Some(burger) = queue.delivered(ctx.component_id.id()).first() {
+                    let mut burger = burger.clone();
+                    burger.move_to(ctx.component_id, "assembly", outputs.timestamp());
+                    outputs.set("burger", burger)?;
+                }
+                if ctx.inputs.get::<bool>("ready").unwrap_or(false) {
+                    queue.request_pop(ctx.component_id.id());
+                    ctx.memory.write("queue", "burgers", queue)?;
+                }
+                Ok(())
+            }
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -523,4 +899,1147 @@ mod tests {
         assert_eq!(engine.current_cycle(), 3);
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_engine_introspection() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let adder1 = sim.add_component(Adder { a: 0, b: 0 });
+        let adder2 = sim.add_component(Adder { a: 0, b: 0 });
+        let writer = sim.add_component(BufferWriter);
+        let buffer = sim.add_memory_component(Buffer { data: 0 });
+        sim.connect_component(adder1.output("sum"), adder2.input("a"))?;
+        sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+        
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        engine.cycle()?;
+        engine.cycle()?;
+        
+        // Output ports
+        let event = engine.last_output(&adder2, "sum").expect("adder2 should emit");
+        assert_eq!(event.get_payload::<i32>()?, &0);
+        assert!(engine.last_output(&writer, "sum").is_none());
+        assert_eq!(engine.output_events().len(), 2);
+        
+        // Memory contents
+        let addresses = engine.memory_addresses(&buffer)?;
+        let names: Vec<&str> = addresses.iter().map(|a| a.address.as_str()).collect();
+        assert_eq!(names, vec!["first", "latest"]);
+        assert!(addresses[0].type_name.ends_with("Buffer"));
+        let latest = engine.read_memory_any(&buffer, "latest").expect("latest should exist");
+        assert_eq!(latest.downcast::<Buffer>().map(|b| b.data).ok(), Some(2));
+        
+        // Connections and execution order
+        assert_eq!(engine.connections().len(), 1);
+        assert_eq!(engine.memory_connections().get(&(writer.clone(), "buffer".to_string())), Some(&buffer));
+        assert_eq!(engine.execution_stages().len(), 2);
+        assert_eq!(engine.execution_stages()[1], vec![adder2.clone()]);
+        assert_eq!(engine.sub_level_stages().len(), 2);
+        assert!(engine.processor_module(&writer).unwrap().has_memory_port("buffer"));
+        assert_eq!(engine.processing_component_ids().len(), 3);
+        assert_eq!(engine.memory_component_ids(), vec![&buffer]);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_stimulus_injection() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let calc = sim.add_component(Calculator);
+        let adder = sim.add_component(Adder { a: 0, b: 0 });
+        let source = sim.add_component(Adder { a: 0, b: 0 });
+        
+        sim.add_stimulus(calc.input("input"), IteratorStimulus::new(vec![1.0f64, 2.5]))?;
+        sim.add_stimulus(adder.input("a"), ClosureStimulus::new(|cycle| Some(cycle as i32 * 10)))?;
+        
+        // A stimulus-driven port cannot also be connected, and vice versa
+        assert!(sim.connect_component(source.output("sum"), adder.input("a")).is_err());
+        sim.connect_component(source.output("sum"), adder.input("b"))?;
+        assert!(sim.add_stimulus(adder.input("b"), IteratorStimulus::new(vec![1i32])).is_err());
+        assert!(sim.add_stimulus(adder.input("missing"), IteratorStimulus::new(vec![1i32])).is_err());
+        
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        
+        let mut results = Vec::new();
+        for _ in 0..3 {
+            engine.cycle()?;
+            results.push(*engine.last_output(&calc, "result").unwrap().get_payload::<f64>()?);
+            let sum = engine.last_output(&adder, "sum").unwrap();
+            assert_eq!(sum.get_payload::<i32>()?, &(engine.current_cycle() as i32 * 10));
+        }
+        
+        // Iterator exhausted after two cycles; Calculator falls back to its default input
+        assert_eq!(results, vec![2.0, 5.0, 2.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_probes_record_time_series() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let calc = sim.add_component(Calculator);
+        let writer = sim.add_component(BufferWriter);
+        let buffer = sim.add_memory_component(Buffer { data: 0 });
+        sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+        sim.add_stimulus(calc.input("input"), ClosureStimulus::new(|cycle| Some(cycle as f64)))?;
+        
+        sim.add_probe(Probe::output("result", calc.output("result")))?;
+        sim.add_probe(Probe::memory("latest", buffer.clone(), "latest")
+            .with_projection(|b: &Buffer| b.data as f64)
+            .every(2))?;
+        assert!(sim.add_probe(Probe::output("result", calc.output("result"))).is_err());
+        assert!(sim.add_probe(Probe::output("bad", calc.output("missing"))).is_err());
+        assert!(sim.add_probe(Probe::memory("bad", calc.clone(), "latest")).is_err());
+        
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        for _ in 0..4 {
+            engine.cycle()?;
+        }
+        
+        let result = engine.probe_series("result").expect("result probe");
+        assert_eq!(result.values(), vec![2.0, 4.0, 6.0, 8.0]);
+        let latest = engine.probe_series("latest").expect("latest probe");
+        assert_eq!(latest.samples.iter().map(|s| s.cycle).collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(latest.values(), vec![2.0, 4.0]);
+        
+        let csv = engine.probe_recorder().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "cycle,latest,result");
+        assert_eq!(lines[1], "1,,2");
+        assert_eq!(lines[2], "2,2,4");
+        Ok(())
+    }
+
+    #[test]
+    fn test_stats_registry_and_memory_counts() -> Result<(), String> {
+        let mut reports = Vec::new();
+        for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode));
+            let tracker = sim.add_component(WaitTracker);
+            let buffer = sim.add_memory_component(Buffer { data: 0 });
+            sim.connect_memory_port(tracker.memory_port("buffer"), buffer.clone())?;
+            sim.add_stimulus(tracker.input("wait"), IteratorStimulus::new(vec![1.0f64, 2.0, 3.0, 4.0]))?;
+            
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            for _ in 0..6 {
+                engine.cycle()?;
+            }
+            
+            let memory = engine.memory_stats(&buffer).expect("memory stats");
+            assert_eq!((memory.read_count, memory.write_count), (1, 1));
+            assert_eq!((memory.total_read_count, memory.total_write_count), (6, 6));
+            reports.push(engine.stats_report());
+        }
+        
+        let report = &reports[0];
+        assert_eq!(report.counters.get("arrivals"), Some(&4));
+        let wait = &report.histograms["wait_time"];
+        assert_eq!((wait.count, wait.mean, wait.min, wait.max), (4, 2.5, 1.0, 4.0));
+        // Queue levels 0,1,2,0,1,2 over cycles 1..=6
+        let queue = &report.time_weighted["queue_length"];
+        assert_eq!((queue.mean, queue.duration), (1.0, 6));
+        assert_eq!(reports[0], reports[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_profiler_report_and_trace() -> Result<(), String> {
+        for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode));
+            let adder1 = sim.add_component(Adder { a: 0, b: 0 });
+            let adder2 = sim.add_component(Adder { a: 0, b: 0 });
+            let writer = sim.add_component(BufferWriter);
+            let buffer = sim.add_memory_component(Buffer { data: 0 });
+            sim.connect_component(adder1.output("sum"), adder2.input("a"))?;
+            sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+            
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            engine.cycle()?;
+            assert!(engine.profiler().is_none());
+            
+            engine.enable_profiling();
+            for _ in 0..3 {
+                engine.cycle()?;
+            }
+            
+            let profiler = engine.profiler().expect("profiling enabled");
+            assert_eq!(profiler.timing(ProfileKind::Component, adder2.id()).map(|t| t.calls), Some(3));
+            assert_eq!(profiler.timing(ProfileKind::MemoryCycle, buffer.id()).map(|t| t.calls), Some(3));
+            assert_eq!(profiler.timing(ProfileKind::MemorySnapshot, buffer.id()).map(|t| t.calls), Some(3));
+            assert_eq!(profiler.timing(ProfileKind::Stage, "stage 1").map(|t| t.calls), Some(3));
+            assert_eq!(profiler.sub_level(0, 0).is_some(), mode == ConcurrencyMode::Rayon);
+            if let Some(timing) = profiler.sub_level(0, 0) {
+                let components = engine.sub_level_stages()[0].sub_levels[0].components.len();
+                assert!((1..=components).contains(&timing.threads), "{} threads for {} components", timing.threads, components);
+            }
+            
+            let report = engine.profile_report().unwrap();
+            assert!(report.entries.windows(2).all(|w| w[0].stats.total >= w[1].stats.total));
+            assert_eq!(report.sub_levels.is_empty(), mode == ConcurrencyMode::Sequential);
+            
+            let trace: serde_json::Value = serde_json::from_str(&profiler.to_chrome_trace())
+                .map_err(|e| e.to_string())?;
+            let events = trace["traceEvents"].as_array().unwrap();
+            assert!(events.iter().any(|e| e["cat"] == "component" && e["ph"] == "X"));
+            
+            assert!(engine.disable_profiling().is_some());
+            assert!(engine.profile_report().is_none());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_component_rng_is_deterministic() -> Result<(), String> {
+        fn run(mode: ConcurrencyMode, seed: u64, cycles: usize) -> Result<(Vec<(f64, f64)>, rsim::core::CycleEngine), String> {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode).with_seed(seed));
+            let a = sim.add_component(NoiseSource);
+            let b = sim.add_component(NoiseSource);
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            let mut draws = Vec::new();
+            for _ in 0..cycles {
+                engine.cycle()?;
+                let value = |id| engine.last_output(id, "value").unwrap().get_payload::<f64>().copied();
+                draws.push((value(&a)?, value(&b)?));
+            }
+            Ok((draws, engine))
+        }
+        
+        let (sequential, _) = run(ConcurrencyMode::Sequential, 42, 5)?;
+        let (parallel, _) = run(ConcurrencyMode::Rayon, 42, 5)?;
+        let (reseeded, _) = run(ConcurrencyMode::Sequential, 43, 5)?;
+        assert_eq!(sequential, parallel);
+        assert_ne!(sequential, reseeded);
+        assert!(sequential.iter().all(|(a, b)| a != b));
+        
+        // Restoring a checkpoint replays the same draws
+        let (_, mut engine) = run(ConcurrencyMode::Sequential, 42, 2)?;
+        let checkpoint = rsim::core::random::RngCheckpoint::from_json(&engine.rng_checkpoint().to_json()?)?;
+        let ids: Vec<_> = engine.processing_component_ids().into_iter().cloned().collect();
+        let mut replay = Vec::new();
+        for _ in 0..2 {
+            engine.restore_rng_checkpoint(&checkpoint)?;
+            engine.cycle()?;
+            replay.push(ids.iter().map(|id| *engine.last_output(id, "value").unwrap().get_payload::<f64>().unwrap()).collect::<Vec<_>>());
+        }
+        assert_eq!(replay[0], replay[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_replication_runner() -> Result<(), String> {
+        let runner = ReplicationRunner::new(|seed| {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_seed(seed));
+            sim.add_component(NoiseSource);
+            sim.build()
+        })
+        .replications(8)
+        .base_seed(100)
+        .cycles(3)
+        .metric("noise", |engine| {
+            let id = engine.processing_component_ids()[0].clone();
+            *engine.last_output(&id, "value").unwrap().get_payload::<f64>().unwrap()
+        })
+        .metric("cycles", |engine| engine.current_cycle() as f64);
+        
+        let results = runner.run()?;
+        assert_eq!(results.seeds, (100..108).collect::<Vec<u64>>());
+        assert_eq!(results, runner.run()?);
+        assert_eq!(results.metric("noise").unwrap()[3], runner.run_one(103)?[0]);
+        
+        let cycles = results.summary("cycles").unwrap();
+        assert_eq!((cycles.mean, cycles.std_dev, cycles.ci95_half_width), (3.0, 0.0, 0.0));
+        let noise = results.summary("noise").unwrap();
+        assert_eq!(noise.count, 8);
+        assert!(noise.std_dev > 0.0 && noise.min >= 0.0 && noise.max < 1.0);
+        assert_eq!(results.to_csv().lines().next(), Some("seed,cycles,noise"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parameter_sweep_with_resume() -> Result<(), String> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        
+        let builds = Arc::new(AtomicUsize::new(0));
+        let counter = builds.clone();
+        let points = DesignBuilder::full_factorial(&[
+            Parameter::integer("x", 1, 3),
+            Parameter::discrete("scale", vec![1.0, 10.0]),
+        ])?;
+        let sweep = ParameterSweep::new(points, move |point, _seed| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let input = point.get("x")? * point.get("scale")?;
+            let mut sim = Simulation::new();
+            let calc = sim.add_component(Calculator);
+            sim.add_stimulus(calc.input("input"), ClosureStimulus::new(move |_| Some(input)))?;
+            sim.build()
+        })
+        .replications(2)
+        .cycles(1)
+        .metric("result", |engine| {
+            let id = engine.processing_component_ids()[0].clone();
+            *engine.last_output(&id, "result").unwrap().get_payload::<f64>().unwrap()
+        });
+        
+        let path = std::env::temp_dir().join(format!("rsim_sweep_{}.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let results = sweep.run_csv(&path)?;
+        assert_eq!(builds.load(Ordering::SeqCst), 12);
+        assert_eq!(results.header(), "point,scale,x,replication,seed,result");
+        assert_eq!(results.point_summary(5, "result").unwrap().mean, 60.0);
+        assert_eq!(results, sweep.run()?);
+        
+        // Simulate an interrupted run: drop the last point's second row and truncate a line
+        let csv = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        let mut lines: Vec<&str> = csv.lines().collect();
+        lines.truncate(lines.len() - 1);
+        let partial = format!("{}\n5,10,3,1,1", lines.join("\n"));
+        std::fs::write(&path, partial).map_err(|e| e.to_string())?;
+        
+        builds.store(0, Ordering::SeqCst);
+        let resumed = sweep.run_csv(&path)?;
+        assert_eq!(builds.load(Ordering::SeqCst), 2);
+        assert_eq!(resumed, results);
+        assert_eq!(std::fs::read_to_string(&path).map_err(|e| e.to_string())?, results.to_csv());
+        std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_warmup_resets_statistics() -> Result<(), String> {
+        let mut sim = Simulation::with_config(SimulationConfig::default().with_warmup_cycles(2));
+        let tracker = sim.add_component(WaitTracker);
+        let buffer = sim.add_memory_component(Buffer { data: 0 });
+        sim.connect_memory_port(tracker.memory_port("buffer"), buffer.clone())?;
+        sim.add_stimulus(tracker.input("wait"), IteratorStimulus::new(vec![1.0f64, 2.0, 3.0, 4.0]))?;
+        sim.add_probe(Probe::memory("queue", buffer.clone(), "queue").with_projection(|b: &Buffer| b.data as f64))?;
+        
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        for _ in 0..6 {
+            engine.cycle()?;
+        }
+        
+        let report = engine.stats_report();
+        assert_eq!(report.counters.get("arrivals"), Some(&2));
+        assert_eq!(report.histograms["wait_time"].mean, 3.5);
+        // Queue levels 2,0,1,2 over cycles 3..=6
+        let queue = &report.time_weighted["queue_length"];
+        assert_eq!((queue.mean, queue.duration), (1.25, 4));
+        assert_eq!(engine.memory_stats(&buffer).unwrap().total_read_count, 4);
+        
+        // Probe series are kept across the reset and can be truncated afterwards
+        let series = engine.probe_series("queue").unwrap();
+        assert_eq!(series.len(), 6);
+        assert_eq!(series.from_cycle(3).values(), vec![0.0, 1.0, 2.0, 0.0]);
+        assert_eq!(series.batch_means(2).unwrap().means, vec![1.0, 1.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_determinism_checker() -> Result<(), String> {
+        let report = DeterminismChecker::new(|config| {
+            let mut sim = Simulation::with_config(config);
+            let adder1 = sim.add_component(Adder { a: 0, b: 0 });
+            let adder2 = sim.add_component(Adder { a: 0, b: 0 });
+            let writer = sim.add_component(BufferWriter);
+            let tracker = sim.add_component(WaitTracker);
+            let buffer = sim.add_memory_component(Buffer { data: 0 });
+            let queue = sim.add_memory_component(Buffer { data: 0 });
+            sim.add_component(NoiseSource);
+            sim.connect_component(adder1.output("sum"), adder2.input("a"))?;
+            sim.connect_memory_port(writer.memory_port("buffer"), buffer)?;
+            sim.connect_memory_port(tracker.memory_port("buffer"), queue)?;
+            sim.build()
+        })
+        .config(SimulationConfig::default().with_seed(9))
+        .cycles(10)
+        .compare::<Buffer>()
+        .run()?;
+        assert!(report.is_deterministic(), "{:?}", report.divergence);
+        assert_eq!(report.cycles_checked, 10);
+        assert!(report.unchecked_types.is_empty());
+        
+        // Without a registered comparison the buffers are skipped, so nothing is proven
+        let report = DeterminismChecker::new(|config| {
+            let mut sim = Simulation::with_config(config);
+            let writer = sim.add_component(BufferWriter);
+            let buffer = sim.add_memory_component(Buffer { data: 0 });
+            sim.connect_memory_port(writer.memory_port("buffer"), buffer)?;
+            sim.build()
+        })
+        .cycles(3)
+        .run()?;
+        assert!(report.divergence.is_none());
+        assert_eq!(report.unchecked_types.iter().filter(|name| name.ends_with("Buffer")).count(), 1);
+        assert!(!report.is_deterministic());
+        
+        let report = DeterminismChecker::new(|config| {
+            // Two components so the sub-level is actually dispatched to the thread pool
+            let mut sim = Simulation::with_config(config);
+            sim.add_component(ThreadReporter);
+            sim.add_component(ThreadReporter);
+            sim.build()
+        })
+        .cycles(10)
+        .run()?;
+        let divergence = report.divergence.expect("thread-dependent output must diverge");
+        assert_eq!(divergence.cycle, 1);
+        assert_eq!(divergence.location, DivergenceLocation::Output {
+            component_id: "ThreadReporter0".to_string(),
+            port: "on_worker".to_string(),
+        });
+        assert_eq!(divergence.detail, "sequential false vs rayon true");
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_and_replay_inputs() -> Result<(), String> {
+        fn run(seed: u64, stimulus: f64, log: Option<InputLog>) -> Result<(Vec<f64>, Option<InputLog>), String> {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_seed(seed));
+            let a = sim.add_component(Sampler);
+            let b = sim.add_component(Sampler);
+            sim.add_stimulus(a.input("input"), ClosureStimulus::new(move |cycle| (cycle % 2 == 1).then_some(stimulus)))?;
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            match log {
+                Some(log) => engine.start_replay(log),
+                None => engine.start_recording(),
+            }
+            let mut values = Vec::new();
+            for _ in 0..4 {
+                engine.cycle()?;
+                for id in [&a, &b] {
+                    values.push(*engine.last_output(id, "value").unwrap().get_payload::<f64>().unwrap());
+                }
+            }
+            Ok((values, engine.take_recording()))
+        }
+        
+        let (recorded, log) = run(7, 5.0, None)?;
+        let log = log.unwrap();
+        assert_eq!(log.cycle(1).unwrap().stimuli.len(), 1);
+        assert!(log.cycle(2).unwrap().stimuli.is_empty());
+        assert_eq!(log.cycle(3).unwrap().external["Sampler0"].len(), 1);
+        assert_eq!(log.cycle(3).unwrap().rng.len(), 2);
+        
+        let path = std::env::temp_dir().join(format!("rsim_input_log_{}.jsonl", std::process::id()));
+        log.save(&path)?;
+        let loaded = InputLog::load(&path)?;
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded, log);
+        
+        // Different seed, stimulus and live clock: replay still reproduces the run
+        let (replayed, _) = run(99, 123.0, Some(loaded))?;
+        assert_eq!(replayed, recorded);
+        let (live, _) = run(7, 5.0, None)?;
+        assert_ne!(live, recorded);
+        Ok(())
+    }
+
+    #[test]
+    fn test_time_travel_rewinds_and_reexecutes() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let sampler = sim.add_component(Sampler);
+        let noise = sim.add_component(NoiseSource);
+        let writer = sim.add_component(BufferWriter);
+        let buffer = sim.add_memory_component(Buffer { data: 0 });
+        sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+        sim.add_stimulus(sampler.input("input"), IteratorStimulus::new((1..).map(|i| i as f64 * 0.5)))?;
+        sim.add_probe(Probe::output("noise", noise.output("value")))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        engine.enable_time_travel(3, 3);
+        
+        let observe = |engine: &rsim::core::CycleEngine| -> (f64, f64, i64) {
+            let value = |id| *engine.last_output(id, "value").unwrap().get_payload::<f64>().unwrap();
+            let latest = engine.read_memory_any(&buffer, "latest").map(|b| b.downcast_ref::<Buffer>().unwrap().data);
+            (value(&sampler), value(&noise), latest.unwrap_or(0))
+        };
+        let mut original = vec![(0.0, 0.0, 0)];
+        for _ in 0..10 {
+            engine.cycle()?;
+            original.push(observe(&engine));
+        }
+        assert_eq!(engine.history().unwrap().snapshot_cycles(), vec![3, 6, 9]);
+        
+        engine.jump_to_cycle(4)?;
+        assert_eq!(engine.current_cycle(), 4);
+        assert_eq!(observe(&engine), original[4]);
+        assert_eq!(engine.probe_series("noise").unwrap().len(), 4);
+        for expected in &original[5..] {
+            engine.cycle()?;
+            assert_eq!(&observe(&engine), expected);
+        }
+        engine.step_back(2)?;
+        assert_eq!(observe(&engine), original[8]);
+        assert!(engine.jump_to_cycle(2).is_err());
+        
+        // Past the latest executed cycle, inputs are live again
+        engine.jump_to_cycle(11)?;
+        assert_eq!(engine.history().unwrap().latest_cycle(), 11);
+        Ok(())
+    }
+
+    #[test]
+    fn test_debugger_breakpoints_and_watchpoints() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let calc = sim.add_component(Calculator);
+        let writer = sim.add_component(BufferWriter);
+        let buffer = sim.add_memory_component(Buffer { data: 0 });
+        sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+        sim.add_stimulus(calc.input("input"), ClosureStimulus::new(|cycle| (cycle == 3).then_some(1.5)))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        engine.enable_time_travel(1, 16);
+        
+        let mut debugger = Debugger::new(engine);
+        debugger.inspector_mut().register::<Buffer>();
+        assert!(debugger.add_breakpoint(calc.clone(), Some("missing")).is_err());
+        let breakpoint = debugger.add_breakpoint(calc.clone(), Some("input"))?;
+        let stops = debugger.run(10)?;
+        assert_eq!(stops, vec![StopReason::Breakpoint { id: breakpoint, cycle: 3, component_id: calc.clone() }]);
+        let printed = debugger.execute(&format!("print {}", calc))?;
+        assert!(printed.contains("in  input = 1.5"), "{}", printed);
+        assert!(printed.contains("out result = 3.0"), "{}", printed);
+        
+        debugger.delete(breakpoint)?;
+        let output = debugger.execute(&format!("watch {} latest == Buffer {{ data: 5 }}", buffer))?;
+        assert!(output.starts_with("Watchpoint"), "{}", output);
+        let output = debugger.execute("continue 100")?;
+        assert!(output.contains("hit at cycle 5"), "{}", output);
+        assert_eq!(debugger.execute(&format!("print {} latest", buffer))?, format!("{} at cycle 5:\n  latest = Buffer {{ data: 5 }}\n", buffer));
+        
+        // Rewinding re-primes watchpoints, so the same condition fires again
+        debugger.execute("back 2")?;
+        assert_eq!(debugger.engine().current_cycle(), 3);
+        let stops = debugger.run(5)?;
+        assert!(matches!(stops.as_slice(), [StopReason::Watchpoint { cycle: 5, .. }]));
+        
+        let changed = debugger.add_watchpoint(buffer.clone(), "latest", WatchCondition::Changed)?;
+        let stops = debugger.step()?;
+        assert!(matches!(stops.as_slice(), [StopReason::Watchpoint { id, cycle: 6, .. }] if *id == changed));
+        assert!(debugger.execute("frobnicate").is_err());
+        
+        // Without a port, evaluation alone is a hit, even for a component that only touches memory
+        debugger.delete(changed)?;
+        let evaluated = debugger.add_breakpoint(writer.clone(), None)?;
+        assert_eq!(debugger.step()?, vec![StopReason::Breakpoint { id: evaluated, cycle: 7, component_id: writer.clone() }]);
+        debugger.engine_mut().suspend(&writer)?;
+        assert!(debugger.step()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invariant_and_response_monitors() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let calc = sim.add_component(Calculator);
+        let writer = sim.add_component(BufferWriter);
+        let buffer = sim.add_memory_component(Buffer { data: 0 });
+        sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+        sim.add_stimulus(calc.input("input"), ClosureStimulus::new(|cycle| (cycle == 1 || cycle == 6).then_some(1.0)))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        engine.set_monitor_history_window(3);
+        
+        engine.add_monitor(Invariant::memory("latest below 4", buffer.clone(), "latest", |b: &Buffer| b.data < 4), OnViolation::Count)?;
+        engine.add_monitor(Invariant::for_each_memory("non-negative", |b: &Buffer| b.data >= 0), OnViolation::Abort)?;
+        let latest = buffer.clone();
+        engine.add_monitor(ResponseProperty::new(
+            "input answered",
+            2,
+            move |engine| !engine.input_events(&calc).is_empty(),
+            move |engine| engine.read_memory_any(&latest, "latest")
+                .is_some_and(|b| b.downcast_ref::<Buffer>().unwrap().data % 4 == 0),
+        ), OnViolation::Count)?;
+        assert!(engine.add_monitor(Invariant::new("non-negative", |_| Ok(Observation::pass(""))), OnViolation::Count).is_err());
+        
+        for _ in 0..8 {
+            engine.cycle()?;
+        }
+        let report = engine.monitor_report();
+        assert_eq!(report.monitors.iter().map(|m| (m.name.as_str(), m.checks, m.violations)).collect::<Vec<_>>(),
+                   vec![("latest below 4", 8, 5), ("non-negative", 8, 0), ("input answered", 8, 1)]);
+        
+        let first = &engine.violations()[0];
+        assert_eq!((first.monitor.as_str(), first.cycle), ("latest below 4", 4));
+        assert_eq!(first.values, format!("{}[latest] = Buffer {{ data: 4 }}", buffer));
+        assert_eq!(first.history.iter().map(|(cycle, _)| *cycle).collect::<Vec<_>>(), vec![2, 3, 4]);
+        let late = engine.violations().iter().find(|v| v.monitor == "input answered").unwrap();
+        assert_eq!((late.cycle, late.detail.as_str()), (4, "trigger from cycle 1 not served within 2 cycles"));
+        
+        // Aborting monitors fail the cycle that violates them
+        engine.add_monitor(Invariant::memory("latest below 10", buffer.clone(), "latest", |b: &Buffer| b.data < 10), OnViolation::Abort)?;
+        engine.cycle()?;
+        let error = engine.cycle().unwrap_err();
+        assert!(error.contains("'latest below 10' violated at cycle 10"), "{}", error);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dynamic_topology_changes() -> Result<(), String> {
+        for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode));
+            let source = sim.add_component(Calculator);
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            engine.cycle()?;
+            
+            // Components added mid-run continue the builder's numbering and can be wired up
+            let doubler = engine.add_component(Calculator);
+            assert_eq!(doubler.id(), "Calculator1");
+            engine.add_connection(source.output("result"), doubler.input("input"))?;
+            assert_eq!(engine.execution_stages(), &[vec![source.clone()], vec![doubler.clone()]]);
+            engine.cycle()?;
+            assert_eq!(engine.last_output(&doubler, "result").unwrap().payload.as_any().downcast_ref::<f64>(), Some(&4.0));
+            
+            // Invalid changes are rejected and leave the topology untouched
+            let error = engine.add_connection(doubler.output("result"), source.input("input")).unwrap_err();
+            assert!(error.contains("Cycle detected"), "{}", error);
+            assert!(engine.add_connection(source.output("result"), doubler.input("input")).unwrap_err().contains("already connected"));
+            assert!(engine.add_connection(source.output("missing"), doubler.input("input")).is_err());
+            assert_eq!(engine.connections().len(), 1);
+            assert_eq!(engine.execution_stages().len(), 2);
+            
+            let writer = engine.add_component(BufferWriter);
+            let buffer = engine.add_memory_component(Buffer { data: 0 });
+            assert!(engine.add_memory_connection(writer.memory_port("buffer"), doubler.clone()).is_err());
+            engine.add_memory_connection(writer.memory_port("buffer"), buffer.clone())?;
+            for _ in 0..3 {
+                engine.cycle()?;
+            }
+            assert_eq!(engine.query_memory_component_data::<Buffer>(&buffer, "latest")?, Some(Buffer { data: 3 }));
+            
+            // Removing a component drops its connections; downstream inputs fall back to defaults
+            engine.remove_component(&source)?;
+            assert!(engine.connections().is_empty());
+            assert_eq!(engine.execution_stages().concat(), vec![writer.clone(), doubler.clone()]);
+            engine.cycle()?;
+            assert_eq!(engine.last_output(&doubler, "result").unwrap().payload.as_any().downcast_ref::<f64>(), Some(&2.0));
+            engine.remove_memory_connection(&writer.memory_port("buffer"))?;
+            assert!(engine.cycle().is_err(), "writer has no memory bound");
+            
+            engine.add_memory_connection(writer.memory_port("buffer"), buffer.clone())?;
+            engine.remove_component(&buffer)?;
+            assert!(engine.memory_connections().is_empty());
+            assert!(engine.remove_component(&buffer).is_err());
+            engine.remove_component(&writer)?;
+            engine.cycle()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_clock_gating_suspends_components() -> Result<(), String> {
+        for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode));
+            let gate = sim.add_component(ShiftGate);
+            let calc = sim.add_component(Calculator);
+            let writer = sim.add_component(BufferWriter);
+            let buffer = sim.add_memory_component(Buffer { data: 0 });
+            sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+            sim.connect_control(gate.output("open"), writer.clone())?;
+            assert!(sim.connect_control(gate.output("missing"), writer.clone()).is_err());
+            sim.add_stimulus(gate.input("enable"), ClosureStimulus::new(|cycle| match cycle {
+                2 => Some(false),
+                5 => Some(true),
+                _ => None,
+            }))?;
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            let latest = |engine: &rsim::core::execution::CycleEngine| {
+                engine.query_memory_component_data::<Buffer>(&buffer, "latest").unwrap().map(|b| b.data)
+            };
+            
+            // The control event in cycle 2 closes the writer for cycles 3 to 5
+            for _ in 0..4 {
+                engine.cycle()?;
+            }
+            assert!(engine.is_suspended(&writer));
+            assert_eq!(latest(&engine), Some(2));
+            engine.cycle()?;
+            engine.cycle()?;
+            assert!(!engine.is_suspended(&writer));
+            assert_eq!(latest(&engine), Some(3));
+            
+            // Suspended processing components emit nothing
+            engine.suspend(&calc)?;
+            engine.cycle()?;
+            assert!(engine.last_output(&calc, "result").is_none());
+            engine.resume(&calc)?;
+            engine.cycle()?;
+            assert!(engine.last_output(&calc, "result").is_some());
+            assert_eq!(latest(&engine), Some(5));
+            
+            // Suspended memory stays frozen and drops writes made while suspended
+            engine.suspend(&buffer)?;
+            assert_eq!(engine.suspended_components(), vec![&buffer]);
+            engine.cycle()?;
+            engine.cycle()?;
+            assert_eq!(latest(&engine), Some(5));
+            engine.resume(&buffer)?;
+            engine.cycle()?;
+            assert_eq!(latest(&engine), Some(6));
+            assert!(engine.suspend(&rsim::core::types::ComponentId::new("Missing0".to_string(), "Missing".to_string())).is_err());
+        }
+        
+        // Control ports must carry bool values
+        let mut sim = Simulation::new();
+        let calc = sim.add_component(Calculator);
+        let other = sim.add_component(Calculator);
+        sim.connect_control(calc.output("result"), other)?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        assert!(engine.cycle().unwrap_err().contains("must emit bool"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_rate_clock_domains() -> Result<(), String> {
+        for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode));
+            let fast = sim.add_component(ShiftGate);
+            let slow = sim.add_component(ShiftGate);
+            let calc = sim.add_component(Calculator);
+            let writer = sim.add_component(BufferWriter);
+            let buffer = sim.add_memory_component(Buffer { data: 0 });
+            sim.connect(fast.output("open"), slow.input("enable"))?;
+            sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+            sim.add_stimulus(fast.input("enable"), ClosureStimulus::new(|cycle| (cycle == 3).then_some(true)))?;
+            sim.add_stimulus(calc.input("input"), ClosureStimulus::new(|cycle| (cycle == 6).then_some(10.0)))?;
+            sim.set_clock_domain(&slow, ClockDomain::every(5))?;
+            sim.set_clock_domain(&calc, ClockDomain::every(4).with_phase(3))?;
+            sim.set_clock_domain(&buffer, ClockDomain::every(4))?;
+            assert!(sim.set_clock_domain(&calc, ClockDomain::every(4).with_phase(4)).is_err());
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            assert_eq!(engine.clock_domain(&slow), ClockDomain::every(5));
+            assert_eq!(engine.clock_domain(&fast), ClockDomain::default());
+            
+            let mut slow_events = Vec::new();
+            let mut calc_events = Vec::new();
+            let mut latest = Vec::new();
+            for _ in 0..12 {
+                engine.cycle()?;
+                let cycle = engine.current_cycle();
+                if engine.last_output(&slow, "open").is_some() {
+                    slow_events.push(cycle);
+                }
+                if let Some(event) = engine.last_output(&calc, "result") {
+                    calc_events.push((cycle, *event.payload.as_any().downcast_ref::<f64>().unwrap()));
+                }
+                latest.push(engine.query_memory_component_data::<Buffer>(&buffer, "latest")?.map(|b| b.data));
+            }
+            
+            // The event sent in cycle 3 waits for the slow domain's tick in cycle 5
+            assert_eq!(slow_events, vec![5]);
+            // The stimulus in cycle 6 is held until cycle 7, then consumed
+            assert_eq!(calc_events, vec![(3, 2.0), (7, 20.0), (11, 2.0)]);
+            // Memory writes become visible only at the memory domain's ticks
+            assert_eq!(latest, vec![None, None, None, Some(1), Some(1), Some(1), Some(1), Some(2), Some(2), Some(2), Some(2), Some(3)]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_restores_initial_state() -> Result<(), String> {
+        let mut sim = Simulation::with_config(SimulationConfig::default().with_seed(7));
+        let noise = sim.add_component(NoiseSource);
+        let writer = sim.add_component(BufferWriter);
+        let buffer = sim.add_memory_component(Buffer { data: 0 });
+        sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+        sim.add_probe(Probe::output("noise", noise.output("value")))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        engine.add_monitor(ResponseProperty::new("never answered", 100, |_| true, |_| false), OnViolation::Count)?;
+        let stages = engine.execution_stages().to_vec();
+        
+        let run = |engine: &mut rsim::core::execution::CycleEngine| -> Result<Vec<f64>, String> {
+            for _ in 0..5 {
+                engine.cycle()?;
+            }
+            Ok(engine.probe_series("noise").unwrap().values())
+        };
+        let first = run(&mut engine)?;
+        assert_eq!(engine.query_memory_component_data::<Buffer>(&buffer, "latest")?, Some(Buffer { data: 5 }));
+        engine.suspend(&writer)?;
+        
+        engine.reset()?;
+        assert_eq!(engine.current_cycle(), 0);
+        assert_eq!(engine.query_memory_component_data::<Buffer>(&buffer, "latest")?, None);
+        assert!(engine.probe_series("noise").unwrap().is_empty());
+        assert!(!engine.is_suspended(&writer));
+        assert_eq!(engine.monitor_report().monitors[0].checks, 0);
+        assert_eq!(engine.execution_stages(), stages.as_slice());
+        
+        // Re-running after a reset repeats the original run exactly
+        assert_eq!(run(&mut engine)?, first);
+        assert_eq!(engine.query_memory_component_data::<Buffer>(&buffer, "latest")?, Some(Buffer { data: 5 }));
+        
+        engine.reset_with_seed(8)?;
+        assert_ne!(run(&mut engine)?, first);
+        Ok(())
+    }
+
+    #[test]
+    fn test_initial_memory_contents() -> Result<(), String> {
+        let path = std::env::temp_dir().join(format!("rsim_memory_init_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"a": {"data": 1}, "b": {"data": 2}}"#).map_err(|e| e.to_string())?;
+        
+        let mut sim = Simulation::new();
+        let writer = sim.add_component(BufferWriter);
+        let buffer = sim.add_memory_component(Buffer { data: 0 });
+        let table = sim.add_memory_component(Buffer { data: 0 });
+        sim.connect_memory_port(writer.memory_port("buffer"), buffer.clone())?;
+        sim.init_memory(&buffer, "latest", Buffer { data: 10 });
+        sim.init_memory_from_file::<Buffer, _>(&table, &path)?;
+        std::fs::remove_file(&path).ok();
+        assert!(sim.init_memory_from_file::<Buffer, _>(&table, &path).is_err());
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        
+        // Initial values are readable before and during the first cycle
+        assert_eq!(engine.query_memory_component_data::<Buffer>(&buffer, "latest")?, Some(Buffer { data: 10 }));
+        assert_eq!(engine.memory_addresses(&table)?.iter().map(|a| a.address.as_str()).collect::<Vec<_>>(), vec!["a", "b"]);
+        engine.cycle()?;
+        assert_eq!(engine.query_memory_component_data::<Buffer>(&buffer, "latest")?, Some(Buffer { data: 11 }));
+        assert_eq!(engine.query_memory_component_data::<Buffer>(&table, "b")?, Some(Buffer { data: 2 }));
+        
+        // Memory, address and type are validated at build
+        let invalid: [fn(&mut Simulation, &rsim::core::types::ComponentId, &rsim::core::types::ComponentId); 4] = [
+            |sim, memory, _| sim.init_memory(memory, "latest", 10i64),
+            |sim, _, calc| sim.init_memory(calc, "latest", Buffer { data: 1 }),
+            |sim, memory, _| sim.init_memory(memory, "", Buffer { data: 1 }),
+            |sim, memory, _| {
+                sim.init_memory(memory, "latest", Buffer { data: 1 });
+                sim.init_memory(memory, "latest", Buffer { data: 2 });
+            },
+        ];
+        for init in invalid {
+            let mut sim = Simulation::new();
+            let calc = sim.add_component(Calculator);
+            let memory = sim.add_memory_component(Buffer { data: 0 });
+            init(&mut sim, &memory, &calc);
+            assert!(sim.build().is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fifo_with_multiple_producers_and_consumers() -> Result<(), String> {
+        let mut runs = Vec::new();
+        for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode));
+            let queue = sim.add_memory_component(Fifo::<Order>::new(3));
+            sim.init_memory(&queue, "orders", Fifo::<Order>::new(3));
+            let mut consumers = Vec::new();
+            for orders in [vec![1u64, 2, 3, 4, 5, 6], vec![101, 102, 103, 104, 105, 106]] {
+                let producer = sim.add_component(OrderProducer);
+                sim.connect_memory_port(producer.memory_port("queue"), queue.clone())?;
+                sim.add_stimulus(producer.input("order"), IteratorStimulus::new(orders))?;
+            }
+            for _ in 0..2 {
+                let consumer = sim.add_component(OrderConsumer);
+                sim.connect_memory_port(consumer.memory_port("queue"), queue.clone())?;
+                consumers.push(consumer);
+            }
+            
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            let mut served = Vec::new();
+            for _ in 0..10 {
+                engine.cycle()?;
+                for consumer in &consumers {
+                    if let Some(event) = engine.last_output(consumer, "served") {
+                        served.push(*event.get_payload::<u64>()?);
+                    }
+                }
+            }
+            
+            let fifo = engine.query_memory_component_data::<Fifo<Order>>(&queue, "orders")?.expect("queue");
+            let stats = fifo.stats().clone();
+            // Every accepted order is served exactly once, oldest first per producer
+            assert!(fifo.is_empty());
+            assert_eq!(stats.pushed + stats.rejected, 12);
+            assert_eq!(stats.popped, stats.pushed);
+            assert_eq!(served.len() as u64, stats.popped);
+            for producer in [0u64, 100] {
+                let ids: Vec<u64> = served.iter().copied().filter(|id| id / 100 == producer / 100).collect();
+                assert!(ids.windows(2).all(|w| w[0] < w[1]));
+            }
+            assert!(stats.max_occupancy <= fifo.capacity());
+            assert_eq!(engine.stats_report().counters.get("served"), Some(&stats.popped));
+            runs.push((served, stats));
+        }
+        assert_eq!(runs[0], runs[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ready_valid_channel_backpressure() -> Result<(), String> {
+        let ready = vec![true, false, false, true, true, false, true, true];
+        let mut runs = Vec::new();
+        for credits in [0, 2] {
+            for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+                let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode));
+                let producer = sim.add_component(ChannelProducer);
+                let consumer = sim.add_component(ChannelConsumer);
+                sim.add_stimulus(consumer.input("ready"), IteratorStimulus::new(ready.clone()))?;
+                let channel = sim.connect_channel(producer.memory_port("out"), consumer.memory_port("in"), Channel::<i64>::with_credits(credits))?;
+                assert!(sim.connect_channel(producer.memory_port("out"), consumer.memory_port("in"), Channel::<i64>::ready_valid()).is_err());
+                
+                let mut engine = sim.build()?;
+                engine.build_execution_order()?;
+                let (mut items, mut refused) = (Vec::new(), 0);
+                for _ in 0..10 {
+                    engine.cycle()?;
+                    if let Some(event) = engine.last_output(&consumer, "item") {
+                        items.push(*event.get_payload::<i64>()?);
+                    }
+                    if !*engine.last_output(&producer, "accepted").unwrap().get_payload::<bool>()? {
+                        refused += 1;
+                    }
+                }
+                
+                // The consumer sees every item once, in order, only as fast as it is ready
+                assert_eq!(items, vec![1, 2, 3, 4, 5]);
+                let channel = engine.query_memory_component_data::<Channel<i64>>(&channel, CHANNEL_ADDRESS)?.expect("channel");
+                let stats = channel.stats().clone();
+                assert_eq!(stats.transfers, 5);
+                assert!(!channel.can_send() && stats.stalls > 0 && refused > 0);
+                assert_eq!(stats.max_buffered, credits);
+                runs.push((credits, items, refused, stats));
+            }
+        }
+        assert_eq!(runs[0], runs[1]);
+        assert_eq!(runs[2], runs[3]);
+        assert!(runs[2].3.stalls < runs[0].3.stalls);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_server_station() -> Result<(), String> {
+        use rsim::library::{self, Arrivals, ArrivalProcess};
+        
+        let mut runs = Vec::new();
+        for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode).with_seed(7));
+            let (arrivals, _) = library::add_with_state(&mut sim, Arrivals, ArrivalProcess::bernoulli(0.6))?;
+            let station = sim.add_component(Station);
+            let resource = StationResource::new(2)
+                .with_discipline(Discipline::Priority)
+                .with_queue_capacity(4)
+                .with_service(ServiceTime::Exponential(3.0));
+            let memory = sim.add_memory_component(resource.clone());
+            sim.init_memory(&memory, RESOURCE_ADDRESS, resource);
+            sim.connect_memory_port(station.memory_port("resource"), memory.clone())?;
+            sim.connect(arrivals.output("arrivals"), station.input("arrival"))?;
+            
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            let mut departed = 0;
+            for _ in 0..500 {
+                engine.cycle()?;
+                if let Some(event) = engine.last_output(&station, "departed") {
+                    departed += *event.get_payload::<u64>()?;
+                }
+            }
+            
+            let resource = engine.query_memory_component_data::<StationResource>(&memory, RESOURCE_ADDRESS)?.expect("resource");
+            let stats = resource.stats().clone();
+            // Every arrival is served, balked or still at the station
+            let present = (resource.queue_length() + resource.busy()) as u64;
+            assert_eq!(stats.arrivals, stats.served + stats.balked + stats.reneged + present);
+            assert_eq!(departed + resource.departures().len() as u64, stats.served);
+            assert!(stats.balked > 0 && stats.max_queue_length <= 4);
+            assert!(resource.utilization() > 0.5 && resource.utilization() <= 1.0);
+            assert!(resource.mean_wait() > 0.0 && resource.mean_sojourn() > resource.mean_wait());
+            runs.push(stats);
+        }
+        assert_eq!(runs[0], runs[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_token_lifecycle_breakdown() -> Result<(), String> {
+        use rsim::library::{self, DelayLine, DelayState, TokenSink, STATE_ADDRESS};
+        
+        let mut logs = Vec::new();
+        for mode in [ConcurrencyMode::Sequential, ConcurrencyMode::Rayon] {
+            let mut sim = Simulation::with_config(SimulationConfig::default().with_concurrency(mode));
+            let counter = sim.add_component(BurgerCounter);
+            let assembler = sim.add_component(BurgerAssembler);
+            let queue = sim.add_memory_component(Fifo::<Token>::new(10));
+            sim.init_memory(&queue, "burgers", Fifo::<Token>::new(10));
+            sim.connect_memory_port(counter.memory_port("queue"), queue.clone())?;
+            sim.connect_memory_port(assembler.memory_port("queue"), queue.clone())?;
+            // Assembly takes two cycles before the burger is handed over
+            let (assembly, _) = library::add_with_state(&mut sim, DelayLine, DelayState::new(2))?;
+            let (sink, log) = library::add_with_state(&mut sim, TokenSink, TokenLog::new())?;
+            sim.connect(assembler.output("burger"), assembly.input("input"))?;
+            sim.connect(assembly.output("output"), sink.input("token"))?;
+            sim.add_stimulus(counter.input("order"), IteratorStimulus::new(vec![101i64, 102, 103, 104, 105, 106]))?;
+            sim.add_stimulus(assembler.input("ready"), IteratorStimulus::new(vec![
+                true, false, false, true, true, false, true, true, true, true, true, true,
+            ]))?;
+            
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            for _ in 0..20 {
+                engine.cycle()?;
+            }
+            logs.push(engine.query_memory_component_data::<TokenLog>(&log, STATE_ADDRESS)?.expect("log"));
+        }
+        assert_eq!(logs[0], logs[1]);
+        
+        let log = &logs[0];
+        assert_eq!(log.len(), 6);
+        for (burger, order) in log.tokens().zip(101i64..) {
+            assert_eq!(burger.get("order"), Some(&AttributeValue::Int(order)));
+            assert_eq!(burger.time_in("assembly"), 2);
+            assert_eq!(burger.latency(), Some(burger.time_in("wait_bread") + 2));
+            assert_eq!(log.trace(burger.id()), Some(burger));
+        }
+        let breakdown = log.breakdown(Some("burger"));
+        let wait = &breakdown.stages["wait_bread"];
+        assert_eq!((wait.count, breakdown.stages["assembly"].mean()), (6, 2.0));
+        assert!(wait.max > wait.min);
+        assert_eq!(breakdown.end_to_end.total, wait.total + 12);
+        assert_eq!(log.to_csv().lines().count(), 13);
+        Ok(())
+    }
+
+    #[test]
+    fn test_monitors_rewind_with_time_travel() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        sim.add_component(Calculator);
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        engine.enable_time_travel(4, 8);
+        engine.add_monitor(ResponseProperty::new("never", 100, |_| true, |_| false), OnViolation::Count)?;
+        engine.add_monitor(ResponseProperty::new("late", 2, |engine| engine.current_cycle() == 1, |_| false), OnViolation::Count)?;
+        for _ in 0..10 {
+            engine.cycle()?;
+        }
+        
+        // Triggers from after the target cycle are forgotten, so re-executed cycles count once
+        engine.jump_to_cycle(5)?;
+        engine.cycle()?;
+        let report = engine.monitor_report();
+        assert_eq!(report.monitors.iter().map(|m| (m.name.as_str(), m.checks, m.violations)).collect::<Vec<_>>(),
+                   vec![("never", 6, 0), ("late", 6, 1)]);
+        assert_eq!(engine.violations().iter().map(|v| v.cycle).collect::<Vec<_>>(), vec![4]);
+        
+        engine.step_back(3)?;
+        assert_eq!(engine.monitor_report().monitors[1].checks, 3);
+        assert!(engine.violations().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_rewinds_stimuli() -> Result<(), String> {
+        fn build(stateful: bool) -> Result<(rsim::core::execution::CycleEngine, [rsim::core::types::ComponentId; 2]), String> {
+            let mut sim = Simulation::new();
+            let listed = sim.add_component(Calculator);
+            let computed = sim.add_component(Calculator);
+            sim.add_stimulus(listed.input("input"), IteratorStimulus::new(vec![1.0f64, 2.5]))?;
+            if stateful {
+                let mut calls = 0.0;
+                sim.add_stimulus(computed.input("input"), ClosureStimulus::new(move |_| {
+                    calls += 1.0;
+                    Some(calls)
+                }))?;
+            } else {
+                sim.add_stimulus(computed.input("input"), ClosureStimulus::stateless(|cycle| Some(cycle as f64)))?;
+            }
+            let mut engine = sim.build()?;
+            engine.build_execution_order()?;
+            Ok((engine, [listed, computed]))
+        }
+        let run = |engine: &mut rsim::core::execution::CycleEngine, ids: &[rsim::core::types::ComponentId]| -> Result<Vec<Option<f64>>, String> {
+            let mut results = Vec::new();
+            for _ in 0..3 {
+                engine.cycle()?;
+                for id in ids {
+                    results.push(engine.last_output(id, "result").map(|event| *event.get_payload::<f64>().unwrap()));
+                }
+            }
+            Ok(results)
+        };
+        
+        let (mut engine, ids) = build(false)?;
+        let first = run(&mut engine, &ids)?;
+        assert_eq!(first, vec![Some(2.0), Some(2.0), Some(5.0), Some(4.0), Some(2.0), Some(6.0)]);
+        engine.reset()?;
+        assert_eq!(run(&mut engine, &ids)?, first);
+        
+        // A closure that may carry state cannot be rewound, and the failed reset changes nothing
+        let (mut engine, ids) = build(true)?;
+        engine.cycle()?;
+        assert!(engine.reset().is_err());
+        assert!(engine.reset_with_seed(3).is_err());
+        assert_eq!(engine.current_cycle(), 1);
+        engine.cycle()?;
+        assert_eq!(engine.last_output(&ids[0], "result").map(|event| *event.get_payload::<f64>().unwrap()), Some(5.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_time_travel_replays_cycles_without_stimuli() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        let calc = sim.add_component(Calculator);
+        let mut polls = 0;
+        sim.add_stimulus(calc.input("input"), ClosureStimulus::new(move |_| {
+            polls += 1;
+            (polls % 3 == 1).then_some(5.0)
+        }))?;
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        engine.enable_time_travel(4, 4);
+        
+        let stimulated = |engine: &rsim::core::CycleEngine| !engine.input_events(&calc).is_empty();
+        let mut original = Vec::new();
+        for _ in 0..8 {
+            engine.cycle()?;
+            original.push(stimulated(&engine));
+        }
+        assert_eq!(original, vec![true, false, false, true, false, false, true, false]);
+        
+        // Re-executed cycles see exactly the original stimuli, including the empty ones
+        engine.jump_to_cycle(2)?;
+        let mut replayed = vec![stimulated(&engine)];
+        while engine.current_cycle() < 8 {
+            engine.cycle()?;
+            replayed.push(stimulated(&engine));
+        }
+        assert_eq!(replayed, original[1..]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_aborting_monitor_keeps_cycle_bookkeeping() -> Result<(), String> {
+        let mut sim = Simulation::new();
+        sim.add_component(Calculator);
+        let mut engine = sim.build()?;
+        engine.build_execution_order()?;
+        engine.enable_time_travel(2, 4);
+        engine.add_monitor(Invariant::new("odd cycles only", |engine| {
+            Ok(if engine.current_cycle() % 2 == 0 { Observation::fail("even", "even cycle") } else { Observation::pass("odd") })
+        }), OnViolation::Abort)?;
+        
+        engine.cycle()?;
+        assert!(engine.cycle().is_err());
+        
+        // The aborted cycle was still committed to the history, so the run can continue and rewind
+        let history = engine.history().unwrap();
+        assert_eq!((history.latest_cycle(), history.snapshot_cycles()), (2, vec![0, 2]));
+        engine.cycle()?;
+        engine.jump_to_cycle(1)?;
+        assert_eq!(engine.current_cycle(), 1);
+        Ok(())
+    }
+}